@@ -1,5 +1,7 @@
+use super::ColorError;
 use std::fmt;
 use std::ops;
+use std::str::FromStr;
 
 /// Construct an angle from degrees. Angles outside of the 0-359° range will be
 /// normalized accordingly.
@@ -25,6 +27,22 @@ pub fn deg(mut degrees: i32) -> Angle {
     Angle::new(degrees as u16)
 }
 
+/// Construct an angle from a continuous number of degrees, such as a hue that is
+/// being animated. The angle is rounded to the nearest whole degree, and angles
+/// outside of the 0-359° range will be normalized accordingly.
+///
+/// # Example
+/// ```
+/// use css_colors::{deg, deg_precise};
+///
+/// assert_eq!(deg_precise(89.6), deg(90));
+/// assert_eq!(deg_precise(359.7), deg(0));
+/// assert_eq!(deg_precise(-90.2), deg(270));
+/// ```
+pub fn deg_precise(degrees: f32) -> Angle {
+    Angle::from_f32_degrees(degrees)
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 /// A struct that represents the number of degrees in a circle.
 /// Legal values range from `0-359`. Anything else is unused.
@@ -42,8 +60,165 @@ impl Angle {
     pub fn degrees(self) -> u16 {
         self.degrees
     }
+
+    /// Scales the rotation represented by `self` by a factor, rounding to the
+    /// nearest degree and wrapping the result back into the `0-359°` range.
+    /// Negative factors rotate in the opposite direction.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::deg;
+    ///
+    /// assert_eq!(deg(90).scale(2.0), deg(180));
+    /// assert_eq!(deg(90).scale(0.5), deg(45));
+    /// assert_eq!(deg(270).scale(2.0), deg(180));
+    /// assert_eq!(deg(90).scale(-1.0), deg(270));
+    /// ```
+    pub fn scale(self, factor: f32) -> Angle {
+        Angle::from_f32_degrees(self.degrees as f32 * factor)
+    }
+
+    /// Returns the distance between `self` and `other` along the shorter arc of
+    /// the circle, which is always between `0°` and `180°`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::deg;
+    ///
+    /// assert_eq!(deg(30).distance(deg(90)), deg(60));
+    /// assert_eq!(deg(350).distance(deg(10)), deg(20));
+    /// assert_eq!(deg(0).distance(deg(180)), deg(180));
+    /// ```
+    pub fn distance(self, other: Angle) -> Angle {
+        let difference = (self - other).degrees();
+
+        Angle::new(difference.min(360 - difference))
+    }
+
+    /// Returns the angle halfway between `self` and `other` along the shorter arc of
+    /// the circle, rounded to the nearest degree. Unlike averaging the degrees, this
+    /// handles the wraparound at `0°`: the midpoint of `350°` and `10°` is `0°`, not `180°`.
+    /// This makes it the right way to find the average of two hues.
+    ///
+    /// When the two angles are exactly opposite, both arcs are equally short, and the
+    /// midpoint is taken going clockwise (towards larger angles) from `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::deg;
+    ///
+    /// assert_eq!(deg(30).bisect(deg(90)), deg(60));
+    /// assert_eq!(deg(350).bisect(deg(10)), deg(0));
+    /// assert_eq!(deg(0).bisect(deg(180)), deg(90));
+    /// ```
+    pub fn bisect(self, other: Angle) -> Angle {
+        let difference = (other - self).degrees() as f32;
+        let difference = if difference > 180.0 {
+            difference - 360.0
+        } else {
+            difference
+        };
+
+        Angle::from_f32_degrees(self.degrees as f32 + difference / 2.0)
+    }
+
+    /// Constructs an angle from a number of full turns, where `1.0` is a whole circle.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, Angle};
+    ///
+    /// assert_eq!(Angle::from_turns(0.5), deg(180));
+    /// assert_eq!(Angle::from_turns(-0.25), deg(270));
+    /// ```
+    pub fn from_turns(turns: f32) -> Angle {
+        Angle::from_f32_degrees(turns * 360.0)
+    }
+
+    /// Constructs an angle from gradians, where `400.0` is a whole circle.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, Angle};
+    ///
+    /// assert_eq!(Angle::from_grads(200.0), deg(180));
+    /// assert_eq!(Angle::from_grads(50.0), deg(45));
+    /// ```
+    pub fn from_grads(grads: f32) -> Angle {
+        Angle::from_f32_degrees(grads * 0.9)
+    }
+
+    /// Constructs an angle from radians, where `2π` is a whole circle.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, Angle};
+    /// use std::f32::consts::PI;
+    ///
+    /// assert_eq!(Angle::from_radians(PI), deg(180));
+    /// assert_eq!(Angle::from_radians(PI / 2.0), deg(90));
+    /// ```
+    pub fn from_radians(radians: f32) -> Angle {
+        Angle::from_f32_degrees(radians.to_degrees())
+    }
+
+    // Rounds to the nearest degree and wraps the result into the `0-359°` range.
+    fn from_f32_degrees(degrees: f32) -> Angle {
+        let degrees = degrees.round().rem_euclid(360.0);
+
+        Angle::new(degrees as u16)
+    }
 }
 
+/// Parses a [CSS angle](https://www.w3.org/TR/css-values-3/#angles) in `deg`,
+/// `grad`, `rad` or `turn` units, normalizing it to degrees. A number without a
+/// unit is treated as degrees, as CSS allows for hues.
+///
+/// # Example
+/// ```
+/// use css_colors::{deg, Angle};
+///
+/// assert_eq!("180deg".parse::<Angle>(), Ok(deg(180)));
+/// assert_eq!("0.5turn".parse::<Angle>(), Ok(deg(180)));
+/// assert_eq!("200grad".parse::<Angle>(), Ok(deg(180)));
+/// assert_eq!("3.14159rad".parse::<Angle>(), Ok(deg(180)));
+/// assert!("180px".parse::<Angle>().is_err());
+/// ```
+impl FromStr for Angle {
+    type Err = ColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = || ColorError::InvalidSyntax(input.to_owned());
+        let value = input.trim().to_ascii_lowercase();
+
+        // `grad` must be checked before `rad`, which it ends with.
+        let (number, unit): (&str, fn(f32) -> Angle) = if let Some(n) = value.strip_suffix("deg") {
+            (n, Angle::from_f32_degrees)
+        } else if let Some(n) = value.strip_suffix("grad") {
+            (n, Angle::from_grads)
+        } else if let Some(n) = value.strip_suffix("rad") {
+            (n, Angle::from_radians)
+        } else if let Some(n) = value.strip_suffix("turn") {
+            (n, Angle::from_turns)
+        } else {
+            (&value, Angle::from_f32_degrees)
+        };
+
+        match f32::from_str(number) {
+            Ok(number) if number.is_finite() => Ok(unit(number)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Formats the angle in whole degrees, e.g. `90deg`.
+///
+/// # Example
+/// ```
+/// use css_colors::deg;
+///
+/// assert_eq!(format!("{}", deg(30)), "30deg");
+/// ```
 impl fmt::Display for Angle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}deg", self.degrees)
@@ -60,6 +235,24 @@ impl ops::Neg for Angle {
     }
 }
 
+/// Converts an angle into its number of degrees, between `0-359`, as with `Angle::degrees`.
+///
+/// # Example
+/// ```
+/// use css_colors::deg;
+///
+/// let degrees: u16 = deg(-90).into();
+///
+/// assert_eq!(degrees, 270);
+/// ```
+impl From<Angle> for u16 {
+    fn from(angle: Angle) -> u16 {
+        angle.degrees()
+    }
+}
+
+/// Adds two angles together, wrapping the result into the `0-359°` range. Since
+/// both angles are always below `360°`, the sum never overflows.
 impl ops::Add for Angle {
     type Output = Angle;
 
@@ -79,6 +272,9 @@ impl ops::Sub for Angle {
     }
 }
 
+/// Multiplies the degrees of two angles together, wrapping the result into the
+/// `0-359°` range. Multiplying two angles has no geometric meaning; to rotate by a
+/// multiple of an angle, use `Angle::scale` instead.
 impl ops::Mul for Angle {
     type Output = Angle;
 
@@ -90,6 +286,9 @@ impl ops::Mul for Angle {
     }
 }
 
+/// Divides the degrees of two angles using integer division, discarding any
+/// remainder. Panics when dividing by a zero-valued `Angle`. To take a fraction of
+/// an angle, use `Angle::scale` instead.
 impl ops::Div for Angle {
     type Output = Angle;
 
@@ -105,9 +304,41 @@ impl ops::Div for Angle {
     }
 }
 
+/// Returns the remainder of dividing the degrees of `self` by those of `other`,
+/// i.e. how far `self` lies into its sector when the color wheel is split into
+/// sectors `other` degrees wide. Paired with `Div`, which gives the index of the
+/// sector, this makes it easy to bucket hues. Panics when dividing by a zero-valued
+/// `Angle`.
+///
+/// # Example
+/// ```
+/// use css_colors::deg;
+///
+/// let hue = deg(200);
+/// let sector = deg(60);
+///
+/// assert_eq!(hue / sector, deg(3));
+/// assert_eq!(hue % sector, deg(20));
+/// assert_eq!(hue - hue % sector, deg(180));
+/// ```
+impl ops::Rem for Angle {
+    type Output = Angle;
+
+    fn rem(self, other: Angle) -> Angle {
+        if other.degrees == 0 {
+            panic!("Cannot divide by zero-valued `Angle`!");
+        }
+
+        Angle {
+            degrees: self.degrees % other.degrees,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use Angle;
+    use std::f32::consts::FRAC_PI_2;
+    use {deg_precise, Angle, ColorError};
 
     #[test]
     fn can_have_degrees() {
@@ -128,6 +359,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn can_ord_angles() {
         assert_eq!(Angle::new(30) < Angle::new(47), true);
         assert_eq!(Angle::new(47) < Angle::new(30), false);
@@ -186,6 +418,97 @@ mod tests {
         assert_eq!(Angle::new(47) * Angle::new(100), Angle::new(20));
     }
 
+    #[test]
+    fn can_scale_angles() {
+        assert_eq!(Angle::new(30).scale(0.0), Angle::new(0));
+        assert_eq!(Angle::new(30).scale(1.0), Angle::new(30));
+        assert_eq!(Angle::new(30).scale(2.0), Angle::new(60));
+        assert_eq!(Angle::new(30).scale(12.0), Angle::new(0));
+        assert_eq!(Angle::new(30).scale(1.5), Angle::new(45));
+        assert_eq!(Angle::new(45).scale(0.5), Angle::new(23));
+        assert_eq!(Angle::new(30).scale(-2.0), Angle::new(300));
+        assert_eq!(Angle::new(359).scale(1000.0), Angle::new(80));
+    }
+
+    #[test]
+    fn can_round_fractional_degrees() {
+        assert_eq!(deg_precise(0.0), Angle::new(0));
+        assert_eq!(deg_precise(0.4), Angle::new(0));
+        assert_eq!(deg_precise(0.5), Angle::new(1));
+        assert_eq!(deg_precise(359.4), Angle::new(359));
+        assert_eq!(deg_precise(359.5), Angle::new(0));
+        assert_eq!(deg_precise(720.25), Angle::new(0));
+        assert_eq!(deg_precise(-0.4), Angle::new(0));
+        assert_eq!(deg_precise(-1.5), Angle::new(358));
+    }
+
+    #[test]
+    fn can_measure_distance() {
+        assert_eq!(Angle::new(0).distance(Angle::new(0)), Angle::new(0));
+        assert_eq!(Angle::new(10).distance(Angle::new(20)), Angle::new(10));
+        assert_eq!(Angle::new(20).distance(Angle::new(10)), Angle::new(10));
+        assert_eq!(Angle::new(0).distance(Angle::new(359)), Angle::new(1));
+        assert_eq!(Angle::new(359).distance(Angle::new(0)), Angle::new(1));
+        assert_eq!(Angle::new(90).distance(Angle::new(270)), Angle::new(180));
+        assert_eq!(Angle::new(100).distance(Angle::new(290)), Angle::new(170));
+    }
+
+    #[test]
+    fn can_bisect_angles() {
+        assert_eq!(Angle::new(0).bisect(Angle::new(0)), Angle::new(0));
+        assert_eq!(Angle::new(10).bisect(Angle::new(30)), Angle::new(20));
+        assert_eq!(Angle::new(30).bisect(Angle::new(10)), Angle::new(20));
+        assert_eq!(Angle::new(10).bisect(Angle::new(11)), Angle::new(11));
+
+        // Across the 0/360 boundary.
+        assert_eq!(Angle::new(350).bisect(Angle::new(10)), Angle::new(0));
+        assert_eq!(Angle::new(10).bisect(Angle::new(350)), Angle::new(0));
+        assert_eq!(Angle::new(340).bisect(Angle::new(0)), Angle::new(350));
+        assert_eq!(Angle::new(359).bisect(Angle::new(3)), Angle::new(1));
+        assert_eq!(Angle::new(300).bisect(Angle::new(100)), Angle::new(20));
+
+        // At the boundary.
+        assert_eq!(Angle::new(0).bisect(Angle::new(20)), Angle::new(10));
+        assert_eq!(Angle::new(0).bisect(Angle::new(340)), Angle::new(350));
+
+        // Opposite angles go clockwise from `self`.
+        assert_eq!(Angle::new(0).bisect(Angle::new(180)), Angle::new(90));
+        assert_eq!(Angle::new(180).bisect(Angle::new(0)), Angle::new(270));
+        assert_eq!(Angle::new(270).bisect(Angle::new(90)), Angle::new(0));
+    }
+
+    #[test]
+    fn can_convert_angle_units() {
+        assert_eq!(Angle::from_turns(0.0), Angle::new(0));
+        assert_eq!(Angle::from_turns(0.25), Angle::new(90));
+        assert_eq!(Angle::from_turns(1.0), Angle::new(0));
+        assert_eq!(Angle::from_turns(1.75), Angle::new(270));
+        assert_eq!(Angle::from_grads(100.0), Angle::new(90));
+        assert_eq!(Angle::from_grads(-100.0), Angle::new(270));
+        assert_eq!(Angle::from_radians(1.0), Angle::new(57));
+        assert_eq!(Angle::from_radians(-FRAC_PI_2), Angle::new(270));
+        assert_eq!(Angle::from_turns(0.9999), Angle::new(0));
+    }
+
+    #[test]
+    fn can_parse_angles() {
+        assert_eq!("90".parse(), Ok(Angle::new(90)));
+        assert_eq!("90deg".parse(), Ok(Angle::new(90)));
+        assert_eq!("-90DEG".parse(), Ok(Angle::new(270)));
+        assert_eq!("400grad".parse(), Ok(Angle::new(0)));
+        assert_eq!("100grad".parse(), Ok(Angle::new(90)));
+        assert_eq!("1.5708rad".parse(), Ok(Angle::new(90)));
+        assert_eq!("0.75turn".parse(), Ok(Angle::new(270)));
+        assert_eq!(" 45deg ".parse(), Ok(Angle::new(45)));
+
+        for invalid in &["", "deg", "90px", "ninety", "90 deg", "infdeg"] {
+            assert_eq!(
+                invalid.parse::<Angle>(),
+                Err(ColorError::InvalidSyntax(invalid.to_string()))
+            );
+        }
+    }
+
     #[test]
     fn test_divide_angles() {
         assert_eq!(Angle::new(30) / Angle::new(1), Angle::new(30));
@@ -197,4 +520,44 @@ mod tests {
 
         assert_eq!(Angle::new(47) / Angle::new(2), Angle::new(23));
     }
+
+    #[test]
+    fn test_rem_angles() {
+        assert_eq!(Angle::new(200) % Angle::new(60), Angle::new(20));
+        assert_eq!(Angle::new(180) % Angle::new(60), Angle::new(0));
+        assert_eq!(Angle::new(59) % Angle::new(60), Angle::new(59));
+        assert_eq!(Angle::new(47) % Angle::new(1), Angle::new(0));
+        assert_eq!(Angle::new(10) % Angle::new(359), Angle::new(10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rem_by_zero() {
+        let _ = Angle::new(30) % Angle::new(0);
+    }
+
+    #[test]
+    fn can_bucket_hues_into_sectors() {
+        let sector = Angle::new(60);
+        let sector_of = |hue: u16| (Angle::new(hue) / sector).degrees();
+        let sector_start = |hue: u16| Angle::new(hue) - Angle::new(hue) % sector;
+
+        assert_eq!(sector_of(0), 0);
+        assert_eq!(sector_of(59), 0);
+        assert_eq!(sector_of(60), 1);
+        assert_eq!(sector_of(200), 3);
+        assert_eq!(sector_of(359), 5);
+
+        assert_eq!(sector_start(0), Angle::new(0));
+        assert_eq!(sector_start(119), Angle::new(60));
+        assert_eq!(sector_start(120), Angle::new(120));
+        assert_eq!(sector_start(359), Angle::new(300));
+    }
+
+    #[test]
+    fn converts_into_degrees() {
+        assert_eq!(u16::from(Angle::new(0)), 0);
+        assert_eq!(u16::from(Angle::new(359)), 359);
+        assert_eq!(u16::from(deg_precise(720.4)), 0);
+    }
 }
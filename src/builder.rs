@@ -0,0 +1,147 @@
+use super::{rgba, ColorError, RGBA};
+
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+/// A builder for assembling an `RGBA` color one channel at a time, e.g. when the
+/// channels arrive separately from a parser or deserializer.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, ColorBuilder};
+///
+/// let tomato = ColorBuilder::new().red(255).green(99).blue(71).alpha(0.5).build();
+///
+/// assert_eq!(tomato, rgba(255, 99, 71, 0.5));
+/// ```
+pub struct ColorBuilder {
+    red: Option<u8>,
+    green: Option<u8>,
+    blue: Option<u8>,
+    alpha: Option<f32>,
+}
+
+impl ColorBuilder {
+    pub fn new() -> Self {
+        ColorBuilder::default()
+    }
+
+    pub fn red(self, red: u8) -> Self {
+        ColorBuilder {
+            red: Some(red),
+            ..self
+        }
+    }
+
+    pub fn green(self, green: u8) -> Self {
+        ColorBuilder {
+            green: Some(green),
+            ..self
+        }
+    }
+
+    pub fn blue(self, blue: u8) -> Self {
+        ColorBuilder {
+            blue: Some(blue),
+            ..self
+        }
+    }
+
+    /// Sets the alpha channel, expressed as a float between `0.0-1.0`.
+    pub fn alpha(self, alpha: f32) -> Self {
+        ColorBuilder {
+            alpha: Some(alpha),
+            ..self
+        }
+    }
+
+    /// Assembles the color, defaulting any missing red, green or blue channel to
+    /// `0` and a missing alpha channel to `1.0`. Like `rgba`, an alpha value
+    /// outside of the 0.0-1.0 range will cause a panic.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgba, ColorBuilder};
+    ///
+    /// assert_eq!(ColorBuilder::new().green(128).build(), rgba(0, 128, 0, 1.0));
+    /// ```
+    pub fn build(self) -> RGBA {
+        rgba(
+            self.red.unwrap_or(0),
+            self.green.unwrap_or(0),
+            self.blue.unwrap_or(0),
+            self.alpha.unwrap_or(1.0),
+        )
+    }
+
+    /// Assembles the color, returning an error if the red, green or blue channel
+    /// was never set, or if the alpha channel falls outside of the 0.0-1.0 range.
+    /// A missing alpha channel still defaults to `1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgba, ColorBuilder, ColorError};
+    ///
+    /// let builder = ColorBuilder::new().red(255).green(99);
+    ///
+    /// assert_eq!(builder.try_build(), Err(ColorError::MissingChannel("blue")));
+    /// assert_eq!(builder.blue(71).try_build(), Ok(rgba(255, 99, 71, 1.0)));
+    /// ```
+    pub fn try_build(self) -> Result<RGBA, ColorError> {
+        let red = self.red.ok_or(ColorError::MissingChannel("red"))?;
+        let green = self.green.ok_or(ColorError::MissingChannel("green"))?;
+        let blue = self.blue.ok_or(ColorError::MissingChannel("blue"))?;
+        let alpha = self.alpha.unwrap_or(1.0);
+
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(ColorError::InvalidAlpha(alpha));
+        }
+
+        Ok(rgba(red, green, blue, alpha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {rgba, ColorBuilder, ColorError};
+
+    #[test]
+    fn defaults_missing_channels() {
+        assert_eq!(ColorBuilder::new().build(), rgba(0, 0, 0, 1.0));
+        assert_eq!(
+            ColorBuilder::new().blue(255).alpha(0.25).build(),
+            rgba(0, 0, 255, 0.25)
+        );
+    }
+
+    #[test]
+    fn later_calls_replace_earlier_ones() {
+        assert_eq!(
+            ColorBuilder::new().red(10).red(20).build(),
+            rgba(20, 0, 0, 1.0)
+        );
+    }
+
+    #[test]
+    fn validates_on_try_build() {
+        let builder = ColorBuilder::new().red(255).green(99).blue(71);
+
+        assert_eq!(builder.try_build(), Ok(rgba(255, 99, 71, 1.0)));
+        assert_eq!(
+            ColorBuilder::new().green(99).blue(71).try_build(),
+            Err(ColorError::MissingChannel("red"))
+        );
+        assert_eq!(
+            builder.alpha(1.5).try_build(),
+            Err(ColorError::InvalidAlpha(1.5))
+        );
+        assert_eq!(
+            builder.alpha(-0.1).try_build(),
+            Err(ColorError::InvalidAlpha(-0.1))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_invalid_alpha() {
+        ColorBuilder::new().alpha(1.5).build();
+    }
+}
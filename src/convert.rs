@@ -0,0 +1,85 @@
+use super::{Color, RGBf, HSL, HSLA, OKLCH, RGB, RGBA};
+
+/// A trait for the types a `Color` can be converted into with `Color::convert`.
+///
+/// Implementing `FromColor` for a type of your own, e.g. a color space this crate
+/// does not support, makes it a target of `convert` alongside the built-in ones.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, Color, FromColor};
+///
+/// struct Gray(u8);
+///
+/// impl FromColor for Gray {
+///     fn from_color<T: Color>(color: T) -> Self {
+///         Gray(color.greyscale().to_rgb().r.as_u8())
+///     }
+/// }
+///
+/// let gray: Gray = rgb(255, 0, 0).convert();
+///
+/// assert_eq!(gray.0, 128);
+/// ```
+pub trait FromColor {
+    /// Converts `color` into `Self`.
+    fn from_color<T: Color>(color: T) -> Self;
+}
+
+impl FromColor for RGB {
+    fn from_color<T: Color>(color: T) -> Self {
+        color.to_rgb()
+    }
+}
+
+impl FromColor for RGBA {
+    fn from_color<T: Color>(color: T) -> Self {
+        color.to_rgba()
+    }
+}
+
+impl FromColor for HSL {
+    fn from_color<T: Color>(color: T) -> Self {
+        color.to_hsl()
+    }
+}
+
+impl FromColor for HSLA {
+    fn from_color<T: Color>(color: T) -> Self {
+        color.to_hsla()
+    }
+}
+
+/// Drops any alpha channel, since `OKLCH` has none.
+impl FromColor for OKLCH {
+    fn from_color<T: Color>(color: T) -> Self {
+        OKLCH::from_rgb(color.to_rgb())
+    }
+}
+
+/// Drops any alpha channel, since `RGBf` has none.
+impl FromColor for RGBf {
+    fn from_color<T: Color>(color: T) -> Self {
+        RGBf::from(color.to_rgb())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsl, hsla, rgb, rgba, Color, RGBf, HSL, HSLA, OKLCH, RGB, RGBA};
+
+    #[test]
+    fn can_convert_to_builtin_types() {
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert_eq!(tomato.convert::<RGB>(), rgb(255, 99, 71));
+        assert_eq!(tomato.convert::<RGBA>(), tomato);
+        assert_eq!(tomato.convert::<HSL>(), tomato.to_hsl());
+        assert_eq!(tomato.convert::<HSLA>(), tomato.to_hsla());
+        assert_eq!(tomato.convert::<OKLCH>(), OKLCH::from_rgb(rgb(255, 99, 71)));
+        assert_eq!(tomato.convert::<RGBf>(), RGBf::from(rgb(255, 99, 71)));
+
+        let salmon: HSLA = hsl(6, 93, 71).convert();
+        assert_eq!(salmon, hsla(6, 93, 71, 1.0));
+    }
+}
@@ -0,0 +1,261 @@
+use super::model::{hex, number};
+use super::{percent, rgb, Color, CssColor, MixSpace, Ratio, RGB};
+
+/// Formats `color` as a [CSS custom property](https://www.w3.org/TR/css-variables-1/)
+/// declaration, e.g. `--brand: rgb(255, 99, 71);`.
+///
+/// The `name` may be given with or without its leading `--`. Since a custom property
+/// name can't contain spaces or most ASCII punctuation, e.g. a `deep navy` swatch from
+/// a `.gpl` file, every run of those characters is replaced with a `-`.
+///
+/// # Example
+/// ```
+/// use css_colors::{css_var, rgb};
+///
+/// let tomato = rgb(255, 99, 71);
+///
+/// assert_eq!(css_var("brand", tomato), "--brand: rgb(255, 99, 71);");
+/// assert_eq!(css_var("--brand", tomato), "--brand: rgb(255, 99, 71);");
+/// assert_eq!(css_var("deep navy", rgb(0, 0, 80)), "--deep-navy: rgb(0, 0, 80);");
+/// ```
+pub fn css_var<T: Color>(name: &str, color: T) -> String {
+    let name = name.trim();
+    let name = name.strip_prefix("--").unwrap_or(name);
+
+    format!("--{}: {};", ident(name), color.to_css())
+}
+
+// Replaces every run of characters that aren't allowed in a CSS identifier with a `-`.
+// Non-ASCII characters are allowed as they are.
+fn ident(name: &str) -> String {
+    let mut ident = String::with_capacity(name.len());
+
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '-' || !c.is_ascii() {
+            ident.push(c);
+        } else if !ident.ends_with('-') {
+            ident.push('-');
+        }
+    }
+
+    ident
+}
+
+/// Formats the CSS Color 5 [`color-mix()`](https://www.w3.org/TR/css-color-5/#color-mix)
+/// function mixing `lhs` and `rhs` in `space`, e.g. `color-mix(in srgb, red 40%, blue)`.
+/// This lets a stylesheet generator leave the mixing to the browser, rather than
+/// emitting the precomputed result of e.g. `Color::mix`.
+///
+/// As with `Color::mix`, `weight` is the proportion of `lhs`; `rhs` gets the rest.
+/// Since a `Ratio` is always between 0% and 100% and every `MixSpace` is a valid
+/// `color-mix()` space, the output is always a valid function. Weights that are a whole
+/// percentage are written as such; any other `Ratio` is written with up to three decimal
+/// places, so that it is not rounded away.
+///
+/// Note that browsers mix translucent colors using premultiplied alpha, so the result
+/// can differ from `Color::mix`, which follows Sass.
+///
+/// # Example
+/// ```
+/// use css_colors::{hsl, percent, rgb, to_css_color_mix, MixSpace};
+///
+/// assert_eq!(
+///     to_css_color_mix(rgb(255, 0, 0), percent(40), rgb(0, 0, 255), MixSpace::Srgb),
+///     "color-mix(in srgb, rgb(255, 0, 0) 40%, rgb(0, 0, 255))"
+/// );
+/// assert_eq!(
+///     to_css_color_mix(hsl(9, 100, 64), percent(50), rgb(0, 0, 0), MixSpace::Oklch),
+///     "color-mix(in oklch, hsl(9, 100%, 64%) 50%, rgb(0, 0, 0))"
+/// );
+/// ```
+pub fn to_css_color_mix<A: Color, B: Color>(
+    lhs: A,
+    weight: Ratio,
+    rhs: B,
+    space: MixSpace,
+) -> String {
+    let percentage = if percent(weight.as_percentage()) == weight {
+        weight.as_percentage().to_string()
+    } else {
+        number(weight.as_f32() * 100.0)
+    };
+
+    format!(
+        "color-mix(in {}, {} {}%, {})",
+        space.css_name(),
+        lhs.to_css(),
+        percentage,
+        rhs.to_css()
+    )
+}
+
+/// Returns whether `input` is a CSS color that this crate can parse, e.g. before
+/// embedding a user-provided color into an inline style.
+///
+/// Accepts the same notations as `CssColor`: hex notation, the `rgb()`, `rgba()`,
+/// `hsl()` and `hsla()` functions, and the CSS color keywords. Anything else,
+/// including a valid color followed by further declarations, is rejected.
+///
+/// # Example
+/// ```
+/// use css_colors::is_valid_css;
+///
+/// assert!(is_valid_css("rgb(255, 99, 71)"));
+/// assert!(is_valid_css("#ff6347"));
+/// assert!(!is_valid_css("rgb(255, 99, 71); background: url(evil)"));
+/// ```
+pub fn is_valid_css(input: &str) -> bool {
+    input.parse::<CssColor>().is_ok()
+}
+
+/// Renders `colors` as a minimal, self-contained HTML snippet of labelled swatches,
+/// e.g. to write a palette to a `.html` file and preview it in a browser.
+///
+/// Each swatch is a `<div>` filled with its color using inline styles, and labelled
+/// with its name and hex code. The label is black or white, whichever contrasts more
+/// with the swatch. Names are HTML-escaped.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, swatches_html};
+///
+/// let html = swatches_html(&[("brand", rgb(255, 99, 71)), ("ink", rgb(0, 0, 128))]);
+///
+/// assert!(html.contains("background: #ff6347; color: #000000;\">brand<br>#ff6347</div>"));
+/// assert!(html.contains("background: #000080; color: #ffffff;\">ink<br>#000080</div>"));
+/// ```
+pub fn swatches_html(colors: &[(&str, RGB)]) -> String {
+    let text_colors = [rgb(0, 0, 0), rgb(255, 255, 255)];
+    let mut html = String::from(
+        "<div style=\"display: flex; flex-wrap: wrap; gap: 8px; font-family: monospace;\">\n",
+    );
+
+    for &(name, color) in colors {
+        let background = hex(color.to_rgba());
+        let text = *color
+            .best_background(&text_colors)
+            .unwrap_or(&text_colors[0]);
+
+        html += &format!(
+            "  <div style=\"width: 120px; height: 80px; padding: 8px; background: {}; color: {};\">{}<br>{}</div>\n",
+            background,
+            hex(text.to_rgba()),
+            escape_html(name),
+            background
+        );
+    }
+
+    html + "</div>\n"
+}
+
+// Escapes the characters with a special meaning in HTML text and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        css_var, hsl, hsla, is_valid_css, percent, rgb, rgba, swatches_html, to_css_color_mix,
+        Color, MixSpace, Ratio,
+    };
+
+    #[test]
+    fn can_format_custom_properties() {
+        assert_eq!(css_var("brand", rgb(0, 0, 0)), "--brand: rgb(0, 0, 0);");
+        assert_eq!(css_var("--brand", rgb(0, 0, 0)), "--brand: rgb(0, 0, 0);");
+        assert_eq!(css_var(" accent ", rgb(0, 0, 0)), "--accent: rgb(0, 0, 0);");
+        assert_eq!(
+            css_var("deep  navy", rgb(0, 0, 0)),
+            "--deep-navy: rgb(0, 0, 0);"
+        );
+        assert_eq!(
+            css_var("ink (dark)", rgb(0, 0, 0)),
+            "--ink-dark-: rgb(0, 0, 0);"
+        );
+        assert_eq!(css_var("café", rgb(0, 0, 0)), "--café: rgb(0, 0, 0);");
+        assert_eq!(
+            css_var("overlay", hsla(0, 0, 0, 0.5)),
+            "--overlay: hsla(0, 0%, 0%, 0.50);"
+        );
+    }
+
+    #[test]
+    fn can_format_color_mix() {
+        let red = rgb(255, 0, 0);
+        let blue = rgba(0, 0, 255, 0.5);
+
+        assert_eq!(
+            to_css_color_mix(red, percent(40), blue, MixSpace::Srgb),
+            "color-mix(in srgb, rgb(255, 0, 0) 40%, rgba(0, 0, 255, 0.50))"
+        );
+        assert_eq!(
+            to_css_color_mix(red, percent(100), blue, MixSpace::Hsl),
+            "color-mix(in hsl, rgb(255, 0, 0) 100%, rgba(0, 0, 255, 0.50))"
+        );
+        assert_eq!(
+            to_css_color_mix(red, percent(0), blue, MixSpace::Oklab),
+            "color-mix(in oklab, rgb(255, 0, 0) 0%, rgba(0, 0, 255, 0.50))"
+        );
+        assert_eq!(
+            to_css_color_mix(hsl(240, 100, 50), Ratio::from_u8(1), red, MixSpace::Oklch),
+            "color-mix(in oklch, hsl(240, 100%, 50%) 0.392%, rgb(255, 0, 0))"
+        );
+    }
+
+    #[test]
+    fn can_validate_css() {
+        for color in &[
+            rgb(255, 99, 71).to_css(),
+            rgba(255, 99, 71, 0.5).to_css(),
+            hsl(9, 100, 64).to_css(),
+            hsla(9, 100, 64, 0.5).to_css(),
+        ] {
+            assert!(is_valid_css(color), "{} is invalid", color);
+        }
+
+        assert!(is_valid_css("tomato"));
+        assert!(is_valid_css("#F63"));
+        assert!(!is_valid_css(""));
+        assert!(!is_valid_css("tomatoes"));
+        assert!(!is_valid_css("rgb(255, 99, 71"));
+        assert!(!is_valid_css("rgb(255, 99, 71)\"><script>"));
+        assert!(!is_valid_css("red; color: blue"));
+    }
+
+    #[test]
+    fn can_render_swatches() {
+        assert_eq!(
+            swatches_html(&[("brand", rgb(255, 99, 71)), ("ink", rgb(0, 0, 128))]),
+            "<div style=\"display: flex; flex-wrap: wrap; gap: 8px; font-family: monospace;\">\n  \
+             <div style=\"width: 120px; height: 80px; padding: 8px; background: #ff6347; color: #000000;\">brand<br>#ff6347</div>\n  \
+             <div style=\"width: 120px; height: 80px; padding: 8px; background: #000080; color: #ffffff;\">ink<br>#000080</div>\n\
+             </div>\n"
+        );
+        assert_eq!(
+            swatches_html(&[]),
+            "<div style=\"display: flex; flex-wrap: wrap; gap: 8px; font-family: monospace;\">\n</div>\n"
+        );
+    }
+
+    #[test]
+    fn escapes_swatch_names() {
+        let html = swatches_html(&[("<b>\"Tom & Jerry's\"</b>", rgb(255, 255, 255))]);
+
+        assert!(html.contains(">&lt;b&gt;&quot;Tom &amp; Jerry&#39;s&quot;&lt;/b&gt;<br>#ffffff<"));
+        assert!(!html.contains("<b>"));
+    }
+}
@@ -0,0 +1,94 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+/// An enum to represent the ways in which constructing a color can fail.
+pub enum ColorError {
+    /// A required channel (e.g. `"red"`) was never provided.
+    MissingChannel(&'static str),
+
+    /// An alpha value fell outside of the `0.0-1.0` range.
+    InvalidAlpha(f32),
+
+    /// A percentage (e.g. a saturation or lightness) fell outside of the `0-100` range.
+    InvalidPercentage(u8),
+
+    /// A string could not be parsed as a CSS color.
+    InvalidSyntax(String),
+
+    /// A string looked like a CSS color keyword, but is not one. When the string is
+    /// close to a keyword, e.g. because of a typo, that keyword is suggested.
+    UnknownName {
+        name: String,
+        did_you_mean: Option<&'static str>,
+    },
+
+    /// A palette file could not be read, e.g. by `Palette::from_gpl`. The message
+    /// describes the problem, including the line number for text formats.
+    InvalidPalette(String),
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ColorError::MissingChannel(channel) => write!(f, "missing {} channel", channel),
+            ColorError::InvalidAlpha(alpha) => {
+                write!(
+                    f,
+                    "invalid alpha {}, expected a value between 0.0 and 1.0",
+                    alpha
+                )
+            }
+            ColorError::InvalidPercentage(percentage) => write!(
+                f,
+                "invalid percentage {}, expected a value between 0 and 100",
+                percentage
+            ),
+            ColorError::InvalidSyntax(ref input) => write!(f, "invalid CSS color \"{}\"", input),
+            ColorError::UnknownName {
+                ref name,
+                did_you_mean,
+            } => {
+                write!(f, "unknown color name \"{}\"", name)?;
+
+                match did_you_mean {
+                    Some(suggestion) => write!(f, ", did you mean \"{}\"?", suggestion),
+                    None => Ok(()),
+                }
+            }
+            ColorError::InvalidPalette(ref reason) => write!(f, "invalid palette: {}", reason),
+        }
+    }
+}
+
+impl Error for ColorError {}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// An error returned when converting a color would put one of its RGB channels
+/// outside of the valid `0.0-1.0` range, i.e. when the color lies outside of the
+/// RGB gamut. See `HSLA::to_rgba_checked`.
+pub struct GamutError {
+    /// The channel that fell out of range, e.g. `"red"`.
+    pub channel: &'static str,
+
+    /// The value computed for the channel.
+    pub value: f32,
+}
+
+impl GamutError {
+    /// How far outside of the `0.0-1.0` range a computed channel may fall and still be
+    /// put down to floating point error, rather than reported as out of gamut.
+    pub const TOLERANCE: f32 = 0.0001;
+}
+
+impl fmt::Display for GamutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} channel {} is out of gamut, expected a value between 0.0 and 1.0",
+            self.channel, self.value
+        )
+    }
+}
+
+impl Error for GamutError {}
@@ -0,0 +1,276 @@
+use super::{Color, RGBf, Ratio, RGB, RGBA};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A struct to represent a multi-stop gradient that can be sampled at any position,
+/// e.g. once per frame of an animation.
+///
+/// Each stop pairs a position with a color. Stops do not need to be given in order:
+/// they are sorted by position when the gradient is built, keeping stops at the same
+/// position in the order given, which creates a hard transition between them.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, Gradient};
+///
+/// let gradient = Gradient::new(vec![
+///     (0.0, rgba(255, 0, 0, 1.0)),
+///     (0.5, rgba(255, 255, 0, 1.0)),
+///     (1.0, rgba(0, 255, 0, 1.0)),
+/// ]);
+///
+/// assert_eq!(gradient.sample(0.25), rgba(255, 128, 0, 1.0));
+/// assert_eq!(gradient.sample(0.75), rgba(128, 255, 0, 1.0));
+/// ```
+pub struct Gradient {
+    stops: Vec<(f32, RGBA)>,
+}
+
+impl Gradient {
+    /// Builds a gradient from `(position, color)` stops.
+    /// An empty list of stops, or a position that is not a finite number, will cause a panic.
+    pub fn new(mut stops: Vec<(f32, RGBA)>) -> Self {
+        assert!(!stops.is_empty(), "a gradient needs at least one stop");
+        assert!(
+            stops.iter().all(|&(position, _)| position.is_finite()),
+            "invalid gradient stop position"
+        );
+
+        stops.sort_by(|&(lhs, _), &(rhs, _)| lhs.partial_cmp(&rhs).unwrap());
+
+        Gradient { stops }
+    }
+
+    /// Returns the stops of the gradient, sorted by position.
+    pub fn stops(&self) -> &[(f32, RGBA)] {
+        &self.stops
+    }
+
+    /// Returns the color of the gradient at position `t`.
+    ///
+    /// Between two stops, each channel (including alpha) is interpolated linearly in
+    /// RGBA. Positions before the first stop or after the last one take the color of
+    /// that stop. At a position shared by several stops, the last of them is used.
+    pub fn sample(&self, t: f32) -> RGBA {
+        let after = self.stops.iter().position(|&(position, _)| position > t);
+
+        let (start, end) = match after {
+            Some(0) => return self.stops[0].1,
+            Some(index) => (self.stops[index - 1], self.stops[index]),
+            None => return self.stops[self.stops.len() - 1].1,
+        };
+
+        let (start_position, start) = start;
+        let (end_position, end) = end;
+        let weight = (t - start_position) / (end_position - start_position);
+
+        let mix = |lhs: Ratio, rhs: Ratio| {
+            Ratio::clamp(lhs.as_f32() + (rhs.as_f32() - lhs.as_f32()) * weight)
+        };
+
+        RGBA {
+            r: mix(start.r, end.r),
+            g: mix(start.g, end.g),
+            b: mix(start.b, end.b),
+            a: mix(start.a, end.a),
+        }
+    }
+}
+
+/// Builds a ramp of `steps` colors by calling `f(base, t)` once per step, where `t`
+/// runs evenly from `0.0` for the first color to `1.0` for the last. A single step
+/// gets `t = 0.0`, and zero steps give an empty ramp.
+///
+/// This leaves every transformation up to `f`, e.g. varying both the lightness and
+/// the saturation of `base` along the ramp, while taking care of the spacing.
+///
+/// # Example
+/// ```
+/// use css_colors::{ramp_with, rgb, Color, Ratio};
+///
+/// let ramp = ramp_with(rgb(255, 99, 71), 3, |base, t| {
+///     base.darken(Ratio::from_f32(t * 0.5)).desaturate(Ratio::from_f32(t * 0.5))
+/// });
+///
+/// assert_eq!(ramp.len(), 3);
+/// assert_eq!(ramp[0], rgb(255, 99, 71));
+/// assert_eq!(ramp[2], rgb(52, 23, 18));
+/// ```
+pub fn ramp_with<F>(base: RGB, steps: usize, f: F) -> Vec<RGB>
+where
+    F: Fn(RGB, f32) -> RGB,
+{
+    let last = steps.saturating_sub(1).max(1) as f32;
+
+    (0..steps).map(|step| f(base, step as f32 / last)).collect()
+}
+
+/// Returns `frames` colors for a hue-rotation animation, such as a rainbow cycle on an
+/// LED strip or a loading spinner. The first frame is `color` itself, and each following
+/// frame spins the hue a further `360° / frames`, keeping the saturation, lightness and
+/// alpha channel of `color`. Zero frames give an empty animation.
+///
+/// The frames cover the half-open range `0-360°`, so the animation can loop: the frame
+/// after the last one (frame `frames`, or frame `0` of the next cycle) is `color` again.
+/// The hues are spun without rounding to whole degrees, so the spacing stays even
+/// whatever the number of frames.
+///
+/// # Example
+/// ```
+/// use css_colors::{hue_rotation_frames, rgb, rgba};
+///
+/// let frames = hue_rotation_frames(rgb(255, 0, 0), 3);
+///
+/// assert_eq!(frames, [rgba(255, 0, 0, 1.0), rgba(0, 255, 0, 1.0), rgba(0, 0, 255, 1.0)]);
+/// ```
+pub fn hue_rotation_frames<T: Color>(color: T, frames: usize) -> Vec<RGBA> {
+    let color = color.to_rgba();
+    let start = RGBf::from(color.to_rgb());
+    let step = 360.0 / frames as f32;
+
+    (0..frames)
+        .map(|frame| {
+            let RGB { r, g, b } = start.spin(step * frame as f32).to_rgb();
+            RGBA {
+                r,
+                g,
+                b,
+                a: color.a,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {deg, hsla, hue_rotation_frames, ramp_with, rgb, rgba, Gradient};
+
+    #[test]
+    fn samples_between_stops() {
+        let gradient = Gradient::new(vec![
+            (0.0, rgba(0, 0, 0, 0.0)),
+            (1.0, rgba(255, 255, 255, 1.0)),
+        ]);
+
+        assert_eq!(gradient.sample(0.0), rgba(0, 0, 0, 0.0));
+        assert_eq!(gradient.sample(0.5), rgba(128, 128, 128, 0.5));
+        assert_eq!(gradient.sample(1.0), rgba(255, 255, 255, 1.0));
+    }
+
+    #[test]
+    fn clamps_to_the_endpoints() {
+        let gradient = Gradient::new(vec![
+            (0.2, rgba(255, 0, 0, 1.0)),
+            (0.8, rgba(0, 0, 255, 1.0)),
+        ]);
+
+        assert_eq!(gradient.sample(-1.0), rgba(255, 0, 0, 1.0));
+        assert_eq!(gradient.sample(0.1), rgba(255, 0, 0, 1.0));
+        assert_eq!(gradient.sample(0.9), rgba(0, 0, 255, 1.0));
+        assert_eq!(gradient.sample(10.0), rgba(0, 0, 255, 1.0));
+    }
+
+    #[test]
+    fn sorts_stops_by_position() {
+        let gradient = Gradient::new(vec![
+            (1.0, rgba(0, 0, 255, 1.0)),
+            (0.0, rgba(255, 0, 0, 1.0)),
+            (0.5, rgba(0, 255, 0, 1.0)),
+        ]);
+        let positions: Vec<f32> = gradient
+            .stops()
+            .iter()
+            .map(|&(position, _)| position)
+            .collect();
+
+        assert_eq!(positions, [0.0, 0.5, 1.0]);
+        assert_eq!(gradient.sample(0.5), rgba(0, 255, 0, 1.0));
+        assert_eq!(gradient.sample(0.75), rgba(0, 128, 128, 1.0));
+    }
+
+    #[test]
+    fn supports_hard_stops() {
+        let gradient = Gradient::new(vec![
+            (0.0, rgba(255, 0, 0, 1.0)),
+            (0.5, rgba(255, 0, 0, 1.0)),
+            (0.5, rgba(0, 0, 255, 1.0)),
+            (1.0, rgba(0, 0, 255, 1.0)),
+        ]);
+
+        assert_eq!(gradient.sample(0.49), rgba(255, 0, 0, 1.0));
+        assert_eq!(gradient.sample(0.5), rgba(0, 0, 255, 1.0));
+    }
+
+    #[test]
+    fn supports_a_single_stop() {
+        let gradient = Gradient::new(vec![(0.5, rgba(255, 99, 71, 1.0))]);
+
+        assert_eq!(gradient.sample(0.0), rgba(255, 99, 71, 1.0));
+        assert_eq!(gradient.sample(0.5), rgba(255, 99, 71, 1.0));
+        assert_eq!(gradient.sample(1.0), rgba(255, 99, 71, 1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_empty_stops() {
+        Gradient::new(vec![]);
+    }
+
+    #[test]
+    fn spaces_ramp_positions_evenly() {
+        // Records each position in the red channel, as a percentage.
+        let positions = |steps: usize| -> Vec<u8> {
+            ramp_with(rgb(0, 0, 0), steps, |_, t| rgb((t * 100.0) as u8, 0, 0))
+                .iter()
+                .map(|color| color.r.as_u8())
+                .collect()
+        };
+
+        assert_eq!(positions(0), []);
+        assert_eq!(positions(1), [0]);
+        assert_eq!(positions(2), [0, 100]);
+        assert_eq!(positions(5), [0, 25, 50, 75, 100]);
+    }
+
+    #[test]
+    fn builds_custom_ramps() {
+        let ramp = ramp_with(rgb(0, 0, 0), 3, |_, t| {
+            let channel = (t * 255.0).round() as u8;
+            rgb(channel, 0, 255 - channel)
+        });
+
+        assert_eq!(ramp, [rgb(0, 0, 255), rgb(128, 0, 127), rgb(255, 0, 0)]);
+    }
+
+    #[test]
+    fn can_rotate_hues_into_frames() {
+        assert_eq!(hue_rotation_frames(rgb(255, 0, 0), 0), []);
+        assert_eq!(
+            hue_rotation_frames(rgb(255, 0, 0), 1),
+            [rgba(255, 0, 0, 1.0)]
+        );
+
+        let tomato = rgba(255, 99, 71, 0.5);
+        let frames = hue_rotation_frames(tomato, 12);
+
+        assert_eq!(frames.len(), 12);
+        assert_eq!(frames[0], tomato);
+        assert!(frames.iter().all(|frame| frame.a == tomato.a));
+
+        // The frames are evenly spaced, and the next cycle starts back at `tomato`.
+        let hues: Vec<u16> = frames
+            .iter()
+            .map(|frame| frame.hsl_hue().degrees())
+            .collect();
+        assert_eq!(
+            hues,
+            [9, 39, 69, 99, 129, 159, 189, 219, 249, 279, 309, 339]
+        );
+
+        // Any color model can be animated.
+        let frame = hue_rotation_frames(hsla(0, 100, 50, 0.25), 4)[2];
+        assert_eq!(
+            (frame.hsl_hue(), frame.a),
+            (deg(180), hsla(0, 100, 50, 0.25).a)
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use super::{deg, percent, Angle, Color, Ratio, RGB, RGBA};
+use super::model;
+use super::{deg, deg_precise, percent, Angle, Color, ColorError, GamutError, Ratio, RGB, RGBA};
 use std::fmt;
 
 /// Constructs a HSL Color from numerical values, similar to the
@@ -34,8 +35,9 @@ pub fn hsl(h: i32, s: u8, l: u8) -> HSL {
 /// the 0-359° range will be normalized accordingly. The saturation
 /// and lightness components are expressed in percentages. Values
 /// outside of the 0-100% range will cause a panic. The alpha value
-/// is expressed as a float. Values outside of the 0.0-1.0 range will
-/// cause a panic.
+/// is expressed as a float between `0.0` (transparent) and `1.0` (opaque).
+/// Values outside of the 0.0-1.0 range will cause a panic. To pass the
+/// alpha as a `0-255` channel, use `hsla_u8`.
 ///
 /// # Example
 /// ```
@@ -56,6 +58,29 @@ pub fn hsla(h: i32, s: u8, l: u8, a: f32) -> HSLA {
     }
 }
 
+/// Constructs a HSLA Color from numerical values, with the alpha value
+/// expressed as a `0-255` channel, where `255` is opaque.
+///
+/// As with `hsla`, hues outside of the 0-359° range will be normalized,
+/// and saturation or lightness values outside of the 0-100% range will
+/// cause a panic.
+///
+/// # Example
+/// ```
+/// use css_colors::{hsla, hsla_u8};
+///
+/// assert_eq!(hsla_u8(6, 93, 71, 255), hsla(6, 93, 71, 1.0));
+/// assert_eq!(hsla_u8(6, 93, 71, 0), hsla(6, 93, 71, 0.0));
+/// ```
+pub fn hsla_u8(h: i32, s: u8, l: u8, a: u8) -> HSLA {
+    HSLA {
+        h: deg(h),
+        s: percent(s),
+        l: percent(l),
+        a: Ratio::from_u8(a),
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 /// A struct to represent how much hue, saturation, and luminosity should be added to create a color.
 /// The hue is a degree on the color wheel; 0 (or 360) is red, 120 is green, 240 is blue.
@@ -63,6 +88,10 @@ pub fn hsla(h: i32, s: u8, l: u8, a: f32) -> HSLA {
 /// The saturation ranges between `0-100`, where `0` is completely desaturated, and `100` is full saturation.
 /// The luminosity ranges between `0-100`, where `0` is no light (black), and `100` is full light (white).
 ///
+/// Constructing the struct directly bypasses any validation: a saturation or
+/// luminosity that is not a whole percentage is rounded when displayed. Prefer
+/// `hsl` or `HSL::try_new`.
+///
 /// For more, see the [CSS Color Spec](https://www.w3.org/TR/2018/REC-css-color-3-20180619/#hsl-color).
 pub struct HSL {
     // hue
@@ -75,8 +104,24 @@ pub struct HSL {
     pub l: Ratio,
 }
 
+/// Formats the color as `hsl(h, s%, l%)`. The alternate form, `{:#}`, shows the color
+/// in the `rgb()`, `hsl()` and hex notations, which helps when debugging conversions.
+///
+/// # Example
+/// ```
+/// use css_colors::hsl;
+///
+/// let tomato = hsl(9, 100, 64);
+///
+/// assert_eq!(format!("{}", tomato), "hsl(9, 100%, 64%)");
+/// assert_eq!(format!("{:#}", tomato), "rgb(255, 99, 71) / hsl(9, 100%, 64%) / #ff6347");
+/// ```
 impl fmt::Display for HSL {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return f.write_str(&model::all_notations(self.to_rgba(), self.to_hsla(), false));
+        }
+
         write!(f, "hsl({}, {}, {})", self.h.degrees(), self.s, self.l)
     }
 }
@@ -112,19 +157,31 @@ impl Color for HSL {
     }
 
     fn saturate(self, amount: Ratio) -> Self {
-        self.to_hsla().saturate(amount).to_hsl()
+        HSL {
+            s: self.s + amount,
+            ..self
+        }
     }
 
     fn desaturate(self, amount: Ratio) -> Self {
-        self.to_hsla().desaturate(amount).to_hsl()
+        HSL {
+            s: self.s - amount,
+            ..self
+        }
     }
 
     fn lighten(self, amount: Ratio) -> Self {
-        self.to_hsla().lighten(amount).to_hsl()
+        HSL {
+            l: self.l + amount,
+            ..self
+        }
     }
 
     fn darken(self, amount: Ratio) -> Self {
-        self.to_hsla().darken(amount).to_hsl()
+        HSL {
+            l: self.l - amount,
+            ..self
+        }
     }
 
     fn fadein(self, amount: Ratio) -> Self::Alpha {
@@ -140,7 +197,10 @@ impl Color for HSL {
     }
 
     fn spin(self, amount: Angle) -> Self {
-        self.to_hsla().spin(amount).to_hsl()
+        HSL {
+            h: self.h + amount,
+            ..self
+        }
     }
 
     fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha {
@@ -156,13 +216,132 @@ impl Color for HSL {
     }
 
     fn greyscale(self) -> Self {
-        self.to_hsla().greyscale().to_hsl()
+        HSL {
+            s: percent(0),
+            ..self
+        }
+    }
+}
+
+impl HSL {
+    /// Constructs a HSL Color like `hsl`, but returns an error instead of
+    /// panicking when the saturation or lightness falls outside of the 0-100% range.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsl, ColorError, HSL};
+    ///
+    /// assert_eq!(HSL::try_new(6, 93, 71), Ok(hsl(6, 93, 71)));
+    /// assert_eq!(HSL::try_new(6, 193, 71), Err(ColorError::InvalidPercentage(193)));
+    /// ```
+    pub fn try_new(h: i32, s: u8, l: u8) -> Result<HSL, ColorError> {
+        Ok(HSL {
+            h: deg(h),
+            s: try_percent(s)?,
+            l: try_percent(l)?,
+        })
+    }
+
+    /// Returns `steps` colors with the given saturation and luminosity, whose hues
+    /// are evenly spaced around the color wheel starting at red (0°).
+    ///
+    /// The sweep covers the half-open range `0-360°`: the last color approaches, but
+    /// does not repeat, the first. To close the loop, e.g. for a gradient that ends
+    /// where it started, push a copy of the first color onto the result. Hues are
+    /// rounded to the nearest whole degree.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsl, percent, HSL};
+    ///
+    /// let sweep = HSL::hue_sweep(percent(100), percent(50), 3);
+    ///
+    /// assert_eq!(sweep, [hsl(0, 100, 50), hsl(120, 100, 50), hsl(240, 100, 50)]);
+    /// ```
+    pub fn hue_sweep(s: Ratio, l: Ratio, steps: usize) -> Vec<HSL> {
+        let start = HSL { h: deg(0), s, l };
+        let step = 360.0 / steps as f32;
+
+        (0..steps)
+            .map(|index| start.spin(deg_precise(step * index as f32)))
+            .collect()
+    }
+
+    /// Returns a copy of `self` with the hue replaced. This is the same as
+    /// `Color::with_hue`, without having to import the trait.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, hsl};
+    ///
+    /// assert_eq!(hsl(6, 93, 71).with_hue(deg(120)), hsl(120, 93, 71));
+    /// ```
+    pub fn with_hue(self, h: Angle) -> Self {
+        HSL { h, ..self }
+    }
+
+    /// Returns a copy of `self` with the saturation replaced.
+    pub fn with_saturation(self, s: Ratio) -> Self {
+        HSL { s, ..self }
+    }
+
+    /// Returns a copy of `self` with the luminosity replaced.
+    pub fn with_lightness(self, l: Ratio) -> Self {
+        HSL { l, ..self }
+    }
+
+    /// Mixes `self` with `other` directly in HSL space, without converting
+    /// either color to RGBA. See `HSLA::mix_hsl` for how the components are
+    /// interpolated.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsl, percent};
+    ///
+    /// let mixed = hsl(0, 100, 50).mix_hsl(hsl(120, 100, 50), percent(50));
+    ///
+    /// assert_eq!(mixed, hsl(60, 100, 50));
+    /// ```
+    pub fn mix_hsl(self, other: HSL, weight: Ratio) -> HSL {
+        self.to_hsla().mix_hsl(other.to_hsla(), weight).to_hsl()
+    }
+}
+
+fn try_percent(percentage: u8) -> Result<Ratio, ColorError> {
+    if percentage > 100 {
+        Err(ColorError::InvalidPercentage(percentage))
+    } else {
+        Ok(percent(percentage))
+    }
+}
+
+// Clamps a computed RGB channel into the `0.0-1.0` range, unless it falls further
+// outside of it than floating point error can explain.
+fn checked_channel(channel: &'static str, value: f32) -> Result<Ratio, GamutError> {
+    let tolerance = GamutError::TOLERANCE;
+
+    if value.is_finite() && (-tolerance..=1.0 + tolerance).contains(&value) {
+        Ok(Ratio::clamp(value))
+    } else {
+        Err(GamutError { channel, value })
     }
 }
 
+// Builds an RGBA color from computed channels, failing on the first one that is out of gamut.
+fn checked_rgba((r, g, b): (f32, f32, f32), a: Ratio) -> Result<RGBA, GamutError> {
+    Ok(RGBA {
+        r: checked_channel("red", r)?,
+        g: checked_channel("green", g)?,
+        b: checked_channel("blue", b)?,
+        a,
+    })
+}
+
 // A function to convert an HSL value (either h, s, or l) into the equivalent, valid RGB value.
-fn to_rgb_value(val: u16, temp_1: f32, temp_2: f32) -> f32 {
-    let value = val as f32 / 360.0;
+// The hue is taken in (possibly fractional) degrees, so that no precision is lost before
+// it is scaled into the `0.0-1.0` range.
+fn to_rgb_value(hue: f32, temp_1: f32, temp_2: f32) -> f32 {
+    let value = hue.rem_euclid(360.0) / 360.0;
 
     if value > (2.0 / 3.0) {
         // value > 0.66667
@@ -179,6 +358,36 @@ fn to_rgb_value(val: u16, temp_1: f32, temp_2: f32) -> f32 {
     }
 }
 
+// Converts a color in HSL, with the saturation and lightness in the `0.0-1.0` range, into
+// its red, green, and blue channels. The hue is taken in (possibly fractional) degrees.
+pub(crate) fn hsl_to_rgb_channels(hue: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    // If the color is not a grey, then we need to create a temporary variable to continue with the algorithm.
+    // If the luminosity is less than 50%, we add 1.0 to the saturation and multiply by the luminosity.
+    // Otherwise, we add the luminosity and saturation, and subtract the product of luminosity and saturation from it.
+    let temp_1 = if l < 0.5 {
+        l * (1.0 + s)
+    } else {
+        (l + s) - (l * s)
+    };
+
+    // Another temporary variable.
+    let temp_2 = (2.0 * l) - temp_1;
+
+    // Create a rotation of 120 degrees in order to divide the angle into thirds.
+    let rotation = 120.0;
+
+    // Then rotate the circle clockwise by 1/3 for the red value, and by 2/3rds for the blue value.
+    let temporary_r = hue + rotation;
+    let temporary_g = hue;
+    let temporary_b = hue - rotation;
+
+    let red = to_rgb_value(temporary_r, temp_1, temp_2);
+    let green = to_rgb_value(temporary_g, temp_1, temp_2);
+    let blue = to_rgb_value(temporary_b, temp_1, temp_2);
+
+    (red, green, blue)
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 /// A struct to represent how much hue, saturation, and luminosity should be added to create a color.
 /// Also handles alpha specifications.
@@ -187,6 +396,10 @@ fn to_rgb_value(val: u16, temp_1: f32, temp_2: f32) -> f32 {
 /// The saturation ranges between `0-100`, where `0` is completely desaturated, and `100` is full saturation.
 /// The luminosity ranges between `0-100`, where `0` is no light (black), and `100` is full light (white).
 ///
+/// Constructing the struct directly bypasses any validation: a saturation or
+/// luminosity that is not a whole percentage is rounded when displayed. Prefer
+/// `hsla` or `HSLA::try_new`.
+///
 /// For more, see the [CSS Color Spec](https://www.w3.org/TR/2018/REC-css-color-3-20180619/#hsla-color).
 pub struct HSLA {
     // hue
@@ -202,8 +415,14 @@ pub struct HSLA {
     pub a: Ratio,
 }
 
+/// Formats the color as `hsla(h, s%, l%, a)`. The alternate form, `{:#}`, shows the color
+/// in the `rgba()`, `hsla()` and hex notations, which helps when debugging conversions.
 impl fmt::Display for HSLA {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return f.write_str(&model::all_notations(self.to_rgba(), *self, true));
+        }
+
         write!(
             f,
             "hsla({}, {}, {}, {:.02})",
@@ -227,51 +446,13 @@ impl Color for HSLA {
     }
 
     fn to_rgba(self) -> RGBA {
-        let HSLA { h, s, l, a } = self;
-
-        // If there is no saturation, the color is a shade of grey.
-        // We can convert the luminosity and set r, g, and b to that value.
-        if s == percent(0) {
-            return RGBA {
-                r: l,
-                g: l,
-                b: l,
-                a,
-            };
-        }
-
-        let s = s.as_f32();
-        let l = l.as_f32();
-
-        // If the color is not a grey, then we need to create a temporary variable to continue with the algorithm.
-        // If the luminosity is less than 50%, we add 1.0 to the saturation and multiply by the luminosity.
-        // Otherwise, we add the luminosity and saturation, and subtract the product of luminosity and saturation from it.
-        let temp_1 = if l < 0.5 {
-            l * (1.0 + s)
-        } else {
-            (l + s) - (l * s)
-        };
-
-        // Another temporary variable.
-        let temp_2 = (2.0 * l) - temp_1;
-
-        // Create a rotation of 120 degrees in order to divide the angle into thirds.
-        let rotation = Angle::new(120);
-
-        // Then rotate the circle clockwise by 1/3 for the red value, and by 2/3rds for the blue value.
-        let temporary_r = (h + rotation).degrees();
-        let temporary_g = h.degrees();
-        let temporary_b = (h - rotation).degrees();
-
-        let red = to_rgb_value(temporary_r, temp_1, temp_2);
-        let green = to_rgb_value(temporary_g, temp_1, temp_2);
-        let blue = to_rgb_value(temporary_b, temp_1, temp_2);
+        let (r, g, b) = self.to_rgb_channels();
 
         RGBA {
-            r: Ratio::from_f32(red),
-            g: Ratio::from_f32(green),
-            b: Ratio::from_f32(blue),
-            a,
+            r: Ratio::clamp(r),
+            g: Ratio::clamp(g),
+            b: Ratio::clamp(b),
+            a: self.a,
         }
     }
 
@@ -375,3 +556,224 @@ impl Color for HSLA {
         }
     }
 }
+
+impl HSLA {
+    /// Constructs a HSLA Color like `hsla`, but returns an error instead of
+    /// panicking when the saturation or lightness falls outside of the 0-100% range,
+    /// or the alpha value falls outside of the 0.0-1.0 range.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsla, ColorError, HSLA};
+    ///
+    /// assert_eq!(HSLA::try_new(6, 93, 71, 0.5), Ok(hsla(6, 93, 71, 0.5)));
+    /// assert_eq!(HSLA::try_new(6, 93, 171, 0.5), Err(ColorError::InvalidPercentage(171)));
+    /// assert_eq!(HSLA::try_new(6, 93, 71, 1.5), Err(ColorError::InvalidAlpha(1.5)));
+    /// ```
+    pub fn try_new(h: i32, s: u8, l: u8, a: f32) -> Result<HSLA, ColorError> {
+        let HSL { h, s, l } = HSL::try_new(h, s, l)?;
+
+        if !(0.0..=1.0).contains(&a) {
+            return Err(ColorError::InvalidAlpha(a));
+        }
+
+        Ok(HSLA {
+            h,
+            s,
+            l,
+            a: Ratio::from_f32(a),
+        })
+    }
+
+    /// Converts `self` into its RGBA representation like `to_rgba`, but returns an error
+    /// instead of clamping when a computed channel falls outside of the `0.0-1.0` range,
+    /// which means that `self` is not representable in RGB.
+    ///
+    /// A channel may overshoot the range by up to `GamutError::TOLERANCE` to allow for
+    /// floating point error, and is then clamped as usual.
+    ///
+    /// Since the saturation and lightness of an `HSLA` are always between 0% and 100%,
+    /// every color that this crate can construct converts within that tolerance, so an
+    /// `Err` means that the conversion itself went wrong, not that the input was invalid.
+    /// This makes it a safeguard for code that must not silently clamp, rather than a
+    /// way of validating colors.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsla, rgba};
+    ///
+    /// assert_eq!(hsla(9, 100, 64, 1.0).to_rgba_checked(), Ok(rgba(255, 99, 71, 1.0)));
+    /// ```
+    pub fn to_rgba_checked(self) -> Result<RGBA, GamutError> {
+        checked_rgba(self.to_rgb_channels(), self.a)
+    }
+
+    // Computes the red, green, and blue channels of `self` as floats, before they are
+    // checked or clamped into the `0.0-1.0` range.
+    fn to_rgb_channels(self) -> (f32, f32, f32) {
+        let HSLA { h, s, l, .. } = self;
+
+        // If there is no saturation, the color is a shade of grey.
+        // We can convert the luminosity and set r, g, and b to that value.
+        if s == percent(0) {
+            let l = l.as_f32();
+
+            return (l, l, l);
+        }
+
+        hsl_to_rgb_channels(f32::from(h.degrees()), s.as_f32(), l.as_f32())
+    }
+
+    /// Returns a copy of `self` with the hue replaced. This is the same as
+    /// `Color::with_hue`, without having to import the trait.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, hsla};
+    ///
+    /// assert_eq!(hsla(6, 93, 71, 0.5).with_hue(deg(120)), hsla(120, 93, 71, 0.5));
+    /// ```
+    pub fn with_hue(self, h: Angle) -> Self {
+        HSLA { h, ..self }
+    }
+
+    /// Returns a copy of `self` with the saturation replaced.
+    pub fn with_saturation(self, s: Ratio) -> Self {
+        HSLA { s, ..self }
+    }
+
+    /// Returns a copy of `self` with the luminosity replaced.
+    pub fn with_lightness(self, l: Ratio) -> Self {
+        HSLA { l, ..self }
+    }
+
+    /// Mixes `self` with `other` directly in HSL space, without converting
+    /// either color to RGBA. The `weight` is the proportion of `self` in the
+    /// result, so `percent(100)` returns `self` and `percent(0)` returns `other`.
+    ///
+    /// Saturation, lightness, and alpha are interpolated linearly. The hue is
+    /// interpolated along the shorter arc of the color wheel, so mixing `350°`
+    /// with `10°` passes through red rather than cyan. A grey has no meaningful
+    /// hue, so when one side is fully desaturated the other side's hue is kept.
+    ///
+    /// The result differs from `mix`, which averages the RGB channels and
+    /// follows Sass: mixing two saturated colors in HSL keeps the saturation
+    /// instead of passing through a duller midpoint.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsla, percent};
+    ///
+    /// let mixed = hsla(350, 100, 50, 1.0).mix_hsl(hsla(10, 100, 50, 0.5), percent(50));
+    ///
+    /// assert_eq!(mixed.h.degrees(), 0);
+    /// assert_eq!(mixed.a.as_percentage(), 75);
+    /// ```
+    pub fn mix_hsl(self, other: HSLA, weight: Ratio) -> HSLA {
+        let w = weight.as_f32();
+        let mix =
+            |lhs: Ratio, rhs: Ratio| Ratio::clamp(lhs.as_f32() * w + rhs.as_f32() * (1.0 - w));
+
+        let h_lhs = f32::from(self.h.degrees());
+        let h_rhs = f32::from(other.h.degrees());
+
+        let hue = if self.s == percent(0) {
+            h_rhs
+        } else if other.s == percent(0) {
+            h_lhs
+        } else {
+            // Take the signed difference along the shorter arc, in the range [-180, 180).
+            let difference = (h_rhs - h_lhs + 540.0) % 360.0 - 180.0;
+            h_lhs + difference * (1.0 - w)
+        };
+
+        HSLA {
+            h: deg(hue.round() as i32),
+            s: mix(self.s, other.s),
+            l: mix(self.l, other.l),
+            a: mix(self.a, other.a),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_channel, checked_rgba, to_rgb_value};
+    use {hsl, hsla, rgba, Color, GamutError, Ratio};
+
+    #[test]
+    fn keeps_fractional_hues() {
+        let (temp_1, temp_2) = (1.0, 0.0);
+
+        // Between 0° and 60°, the value rises linearly from `temp_2` to `temp_1`.
+        assert_eq!(to_rgb_value(0.0, temp_1, temp_2), 0.0);
+        assert!((to_rgb_value(30.0, temp_1, temp_2) - 0.5).abs() < 0.0001);
+        assert!((to_rgb_value(30.5, temp_1, temp_2) - 30.5 / 60.0).abs() < 0.0001);
+        assert!(to_rgb_value(30.25, temp_1, temp_2) < to_rgb_value(30.75, temp_1, temp_2));
+
+        // Hues outside of the 0-360° range wrap around.
+        assert_eq!(
+            to_rgb_value(390.5, temp_1, temp_2),
+            to_rgb_value(30.5, temp_1, temp_2)
+        );
+        assert_eq!(
+            to_rgb_value(-89.5, temp_1, temp_2),
+            to_rgb_value(270.5, temp_1, temp_2)
+        );
+    }
+
+    #[test]
+    fn checks_gamut_on_conversion() {
+        for &(h, s, l) in &[(0, 0, 0), (9, 100, 64), (240, 100, 100), (359, 1, 99)] {
+            let color = hsla(h, s, l, 0.5);
+
+            assert_eq!(color.to_rgba_checked(), Ok(color.to_rgba()));
+        }
+
+        assert_eq!(
+            hsl(120, 100, 50)
+                .to_hsla()
+                .to_rgba_checked()
+                .map(|color| color.to_rgb()),
+            Ok(hsl(120, 100, 50).to_rgb())
+        );
+
+        // Channels that fall out of gamut are reported rather than clamped.
+        let alpha = Ratio::from_f32(0.5);
+        assert_eq!(
+            checked_rgba((1.0, 0.5, 0.0), alpha),
+            Ok(rgba(255, 128, 0, 0.5))
+        );
+        assert_eq!(
+            checked_rgba((1.2, 0.5, 0.0), alpha),
+            Err(GamutError {
+                channel: "red",
+                value: 1.2
+            })
+        );
+        assert_eq!(
+            checked_rgba((0.5, 0.5, -0.2), alpha).unwrap_err().channel,
+            "blue"
+        );
+    }
+
+    #[test]
+    fn checks_channels_within_tolerance() {
+        assert_eq!(checked_channel("red", 0.5), Ok(Ratio::from_f32(0.5)));
+        assert_eq!(checked_channel("red", 1.00001), Ok(Ratio::from_f32(1.0)));
+        assert_eq!(checked_channel("red", -0.00001), Ok(Ratio::from_f32(0.0)));
+        assert_eq!(
+            checked_channel("green", 1.1),
+            Err(GamutError {
+                channel: "green",
+                value: 1.1
+            })
+        );
+        assert!(checked_channel("blue", -0.01).is_err());
+        assert!(checked_channel("blue", f32::NAN).is_err());
+        assert_eq!(
+            checked_channel("green", 1.1).unwrap_err().to_string(),
+            "green channel 1.1 is out of gamut, expected a value between 0.0 and 1.0"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use super::oklch::{from_linear, to_linear};
+use super::{Ratio, RGB};
+
+// The reference white of sRGB (D65), in CIE XYZ.
+const WHITE: (f32, f32, f32) = (0.950_47, 1.0, 1.088_83);
+
+// Converts a color into CIELAB (`L*`, `a*`, `b*`), relative to the D65 white point.
+// The matrix is the standard linear sRGB to XYZ conversion from IEC 61966-2-1.
+pub(crate) fn to_lab(color: RGB) -> (f32, f32, f32) {
+    let r = to_linear(color.r.as_f32());
+    let g = to_linear(color.g.as_f32());
+    let b = to_linear(color.b.as_f32());
+
+    let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+    let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+    let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+
+    let fx = f(x / WHITE.0);
+    let fy = f(y / WHITE.1);
+    let fz = f(z / WHITE.2);
+
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+// Converts a CIELAB color, relative to the D65 white point, back into RGB, clamping
+// any channel that falls outside of the sRGB gamut.
+pub(crate) fn from_lab(l: f32, a: f32, b: f32) -> RGB {
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+
+    let x = f_inverse(fx) * WHITE.0;
+    let y = f_inverse(fy) * WHITE.1;
+    let z = f_inverse(fz) * WHITE.2;
+
+    let r = 3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z;
+    let g = -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z;
+    let b = 0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z;
+
+    RGB {
+        r: Ratio::clamp(from_linear(r)),
+        g: Ratio::clamp(from_linear(g)),
+        b: Ratio::clamp(from_linear(b)),
+    }
+}
+
+// The CIE 1976 color difference: the straight-line distance between two colors in CIELAB.
+pub(crate) fn delta_e(lhs: RGB, rhs: RGB) -> f32 {
+    let (l_lhs, a_lhs, b_lhs) = to_lab(lhs);
+    let (l_rhs, a_rhs, b_rhs) = to_lab(rhs);
+
+    ((l_lhs - l_rhs).powi(2) + (a_lhs - a_rhs).powi(2) + (b_lhs - b_rhs).powi(2)).sqrt()
+}
+
+// The nonlinear compression of each XYZ component, with a linear segment near black.
+fn f(t: f32) -> f32 {
+    const EPSILON: f32 = 216.0 / 24389.0;
+    const KAPPA: f32 = 24389.0 / 27.0;
+
+    if t > EPSILON {
+        t.cbrt()
+    } else {
+        (KAPPA * t + 16.0) / 116.0
+    }
+}
+
+// The inverse of `f`.
+fn f_inverse(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lab::{delta_e, from_lab, to_lab};
+    use rgb;
+
+    #[test]
+    fn can_convert_to_lab() {
+        let (l, a, b) = to_lab(rgb(255, 255, 255));
+        assert!((l - 100.0).abs() < 0.01 && a.abs() < 0.01 && b.abs() < 0.01);
+
+        assert_eq!(to_lab(rgb(0, 0, 0)), (0.0, 0.0, 0.0));
+
+        let (l, a, b) = to_lab(rgb(255, 0, 0));
+        assert!((l - 53.24).abs() < 0.05);
+        assert!((a - 80.09).abs() < 0.05);
+        assert!((b - 67.20).abs() < 0.05);
+    }
+
+    #[test]
+    fn can_convert_from_lab() {
+        for &color in &[
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+            rgb(255, 99, 71),
+            rgb(12, 200, 180),
+        ] {
+            let (l, a, b) = to_lab(color);
+            assert_eq!(from_lab(l, a, b), color);
+        }
+    }
+
+    #[test]
+    fn can_measure_delta_e() {
+        assert_eq!(delta_e(rgb(255, 99, 71), rgb(255, 99, 71)), 0.0);
+        assert!((delta_e(rgb(0, 0, 0), rgb(255, 255, 255)) - 100.0).abs() < 0.01);
+        assert!(delta_e(rgb(128, 128, 128), rgb(129, 128, 128)) < 1.0);
+    }
+}
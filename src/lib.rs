@@ -1,16 +1,43 @@
 mod angle;
+mod builder;
+mod convert;
+mod css;
+mod error;
+mod gradient;
 mod hsl;
+mod lab;
+mod lookup;
+mod luminance;
+mod model;
+pub mod named;
+mod oklch;
+mod palette;
+mod palette_format;
+mod parse;
 mod ratio;
 mod rgb;
+mod rgbf;
 
 pub use angle::*;
+pub use builder::*;
+pub use convert::*;
+pub use css::*;
+pub use error::*;
+pub use gradient::*;
 pub use hsl::*;
+pub use lookup::*;
+pub use luminance::{Luminance, REC601, REC709};
+pub use model::{convert_to, ColorModel, MixSpace};
+pub use oklch::*;
+pub use palette::*;
+pub use parse::*;
 pub use ratio::*;
 pub use rgb::*;
+pub use rgbf::*;
 
 /// A trait that can be used for converting between different color models
 /// and performing various transformations on them.
-pub trait Color {
+pub trait Color: Sized {
     type Alpha: Color;
 
     /// Converts `self` to its CSS string format.
@@ -27,9 +54,28 @@ pub trait Color {
     /// ```
     fn to_css(self) -> String;
 
+    /// Same as `to_css()`, but in debug builds also asserts that the output is a
+    /// color `is_valid_css()` accepts. This catches a misbehaving `Color`
+    /// implementation before its output is embedded in e.g. an inline style.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(250, 128, 114).to_css_validated(), "rgb(250, 128, 114)");
+    /// ```
+    fn to_css_validated(self) -> String {
+        let css = self.to_css();
+        debug_assert!(is_valid_css(&css), "invalid CSS color \"{}\"", css);
+
+        css
+    }
+
     /// Converts `self` into its RGB representation.
     /// When converting from a color model that supports an alpha channel
     /// (e.g. RGBA), the alpha value will not be preserved.
+    /// Converting back with `to_rgba()` then yields a fully opaque color, so
+    /// `rgba.to_rgb().to_rgba()` is intentionally lossy.
     ///
     /// # Examples
     /// ```
@@ -44,6 +90,7 @@ pub trait Color {
     /// Converts `self` into its RGBA representation.
     /// When converting from a color model that does not supports an alpha channel
     /// (e.g. RGB), it will be treated as fully opaque.
+    /// For RGB colors this is lossless: `rgb.to_rgba().to_rgb()` always equals `rgb`.
     ///
     /// # Examples
     /// ```
@@ -55,6 +102,25 @@ pub trait Color {
     /// ```
     fn to_rgba(self) -> RGBA;
 
+    /// Returns the sRGB channels of `self` as `[r, g, b, a]`, each normalized to the
+    /// `0.0-1.0` range, e.g. to hand the color to a shader or a math library.
+    /// Color models without an alpha channel (e.g. RGB) are treated as fully opaque,
+    /// with an alpha of `1.0`. Other models are converted to RGBA first.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 0, 0).channels_f32(), [1.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(rgba(0, 0, 255, 0.0).channels_f32(), [0.0, 0.0, 1.0, 0.0]);
+    /// assert_eq!(hsl(0, 0, 100).channels_f32(), [1.0, 1.0, 1.0, 1.0]);
+    /// ```
+    fn channels_f32(self) -> [f32; 4] {
+        let RGBA { r, g, b, a } = self.to_rgba();
+
+        [r.as_f32(), g.as_f32(), b.as_f32(), a.as_f32()]
+    }
+
     /// Converts `self` into its HSL representation.
     /// When converting from a color model that supports an alpha channel
     /// (e.g. RGBA), the alpha value will not be preserved.
@@ -87,10 +153,429 @@ pub trait Color {
     /// ```
     fn to_hsla(self) -> HSLA;
 
+    /// Converts `self` into any type that implements `FromColor`, which includes every
+    /// color type of this crate as well as `OKLCH`. Unlike the `to_*` methods, the set
+    /// of targets is open: other crates can add their own by implementing `FromColor`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsl, HSL, OKLCH};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// let converted: HSL = tomato.convert();
+    /// assert_eq!(converted, hsl(9, 100, 64));
+    ///
+    /// let oklch = tomato.convert::<OKLCH>();
+    /// assert!((oklch.l - 0.696).abs() < 0.001);
+    /// ```
+    fn convert<T: FromColor>(self) -> T {
+        T::from_color(self)
+    }
+
+    /// Returns the alpha channel of `self`.
+    /// Color models that do not support an alpha channel (e.g. RGB) are always fully opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, percent, rgb, hsla};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).alpha(), percent(100));
+    /// assert_eq!(hsla(9, 100, 64, 0.0).alpha(), percent(0));
+    /// ```
+    fn alpha(self) -> Ratio {
+        self.to_rgba().a
+    }
+
+    /// Returns whether `self` is fully transparent.
+    /// Color models without an alpha channel (e.g. RGB) are never transparent.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert!(rgba(255, 99, 71, 0.0).is_transparent());
+    /// assert!(!rgba(255, 99, 71, 0.5).is_transparent());
+    /// assert!(!rgb(255, 99, 71).is_transparent());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_transparent(self) -> bool {
+        self.alpha() == percent(0)
+    }
+
+    /// Returns whether `self` is fully opaque.
+    /// Color models without an alpha channel (e.g. RGB) are always opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert!(rgba(255, 99, 71, 1.0).is_opaque());
+    /// assert!(!rgba(255, 99, 71, 0.5).is_opaque());
+    /// assert!(rgb(255, 99, 71).is_opaque());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_opaque(self) -> bool {
+        self.alpha() == percent(100)
+    }
+
+    /// Returns whether `self` is partially transparent, i.e. neither fully
+    /// transparent nor fully opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert!(rgba(255, 99, 71, 0.5).is_translucent());
+    /// assert!(!rgba(255, 99, 71, 0.0).is_translucent());
+    /// assert!(!rgb(255, 99, 71).is_translucent());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_translucent(self) -> bool {
+        let alpha = self.alpha();
+
+        alpha != percent(0) && alpha != percent(100)
+    }
+
+    /// Returns the relative luminance of `self`, i.e. how bright it appears to the eye.
+    /// Any alpha channel is ignored, so translucent colors are treated as opaque; use
+    /// `relative_luminance_over` to account for what shows through them.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let white = rgb(255, 255, 255);
+    /// let navy = rgb(0, 0, 128);
+    ///
+    /// assert!(white.luminance() > navy.luminance());
+    /// assert_eq!(navy.luminance(), rgba(0, 0, 128, 0.5).luminance());
+    /// assert!((white.luminance().contrast(navy.luminance()) - 16.0).abs() < 0.1);
+    /// ```
+    fn luminance(self) -> Luminance {
+        luminance::relative_luminance(self.to_rgb())
+    }
+
+    /// Returns the relative luminance of `self` once composited over `background`, as
+    /// a float between `0.0` and `1.0`. A translucent color has no luminance of its own,
+    /// since part of the backdrop shows through it, so this is the value to use when
+    /// checking the contrast of semi-transparent text. Any alpha channel of
+    /// `background` is ignored, treating it as opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let translucent_gray = rgba(128, 128, 128, 0.5);
+    ///
+    /// assert!(translucent_gray.relative_luminance_over(rgb(255, 255, 255)) > 0.5);
+    /// assert!(translucent_gray.relative_luminance_over(rgb(0, 0, 0)) < 0.1);
+    /// ```
+    fn relative_luminance_over<T: Color>(self, background: T) -> f32 {
+        let composited = background.to_rgb().to_rgba().stack(self.to_rgba());
+
+        composited.luminance().as_f32()
+    }
+
+    /// Returns the candidate background with the highest contrast against `self`, e.g.
+    /// to pick which of a theme's backgrounds keeps a given text color readable.
+    /// Contrast is the WCAG contrast ratio of the relative luminances, so any alpha
+    /// channel is ignored. When several candidates tie, the first one in `candidates`
+    /// wins. Returns `None` if `candidates` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let backgrounds = [rgb(255, 99, 71), rgb(0, 0, 128), rgb(240, 240, 240)];
+    ///
+    /// assert_eq!(rgb(255, 255, 255).best_background(&backgrounds), Some(&rgb(0, 0, 128)));
+    /// assert_eq!(rgb(0, 0, 0).best_background(&backgrounds), Some(&rgb(240, 240, 240)));
+    /// assert_eq!(rgb(0, 0, 0).best_background(&[]), None);
+    /// ```
+    fn best_background(self, candidates: &[RGB]) -> Option<&RGB> {
+        let luminance = self.luminance();
+        let mut best: Option<(&RGB, f32)> = None;
+
+        for candidate in candidates {
+            let contrast = luminance.contrast(candidate.luminance());
+
+            match best {
+                Some((_, best_contrast)) if best_contrast >= contrast => {}
+                _ => best = Some((candidate, contrast)),
+            }
+        }
+
+        best.map(|(candidate, _)| candidate)
+    }
+
+    /// Returns the perceptual lightness of `self`, between `0.0` (black) and `1.0` (white).
+    /// Like `oklch_chroma` and `oklch_hue`, this comes from the [OKLCH](struct.OKLCH.html) color
+    /// space, so the same value looks equally light whatever the hue. It is not the HSL
+    /// lightness that `with_lightness` sets. Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsl};
+    ///
+    /// assert!((rgb(255, 0, 0).oklch_lightness() - 0.628).abs() < 0.001);
+    /// assert!(hsl(60, 100, 50).oklch_lightness() > hsl(240, 100, 50).oklch_lightness());
+    /// ```
+    fn oklch_lightness(self) -> f32 {
+        OKLCH::from_rgb(self.to_rgb()).l
+    }
+
+    /// Returns the perceptual chroma, or colorfulness, of `self` in the [OKLCH](struct.OKLCH.html)
+    /// color space. Greys have a chroma of `0.0`, and colors inside sRGB stay below `0.37`.
+    /// Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// assert!((rgb(255, 0, 0).oklch_chroma() - 0.258).abs() < 0.001);
+    /// assert!(rgb(128, 128, 128).oklch_chroma() < 0.001);
+    /// ```
+    fn oklch_chroma(self) -> f32 {
+        OKLCH::from_rgb(self.to_rgb()).c
+    }
+
+    /// Returns the perceptual hue of `self` in the [OKLCH](struct.OKLCH.html) color space,
+    /// rounded to the nearest degree. This is not the same angle as the HSL hue that
+    /// `with_hue` sets: red sits at around `29°`, for instance. Greys have no meaningful hue.
+    /// Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, deg};
+    ///
+    /// assert_eq!(rgb(255, 0, 0).oklch_hue(), deg(29));
+    /// assert_eq!(rgb(0, 0, 255).oklch_hue(), deg(264));
+    /// ```
+    fn oklch_hue(self) -> Angle {
+        deg_precise(OKLCH::from_rgb(self.to_rgb()).h)
+    }
+
+    /// Returns a key that arranges colors into a visually smooth strip when sorted in
+    /// ascending order, e.g. for the legend of a palette. Colors are grouped into twelve
+    /// hue families, each 30° of the HSL color wheel wide and starting with the reds
+    /// centered on 0°. Within a family, colors are ordered by their perceptual `oklch_lightness`.
+    ///
+    /// The key is the index of the family (`0-11`), plus the lightness scaled into
+    /// `0.0-1.0`, so the hue family always outweighs the lightness. Greys (any color
+    /// whose channels all lie within roughly 10% of each other) have no meaningful hue,
+    /// so they form a thirteenth family at the end. Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let mut colors = vec![rgb(128, 128, 128), rgb(0, 0, 255), rgb(255, 160, 160), rgb(128, 0, 0)];
+    ///
+    /// colors.sort_by(|lhs, rhs| {
+    ///     lhs.perceptual_sort_key().partial_cmp(&rhs.perceptual_sort_key()).unwrap()
+    /// });
+    ///
+    /// assert_eq!(colors, [rgb(128, 0, 0), rgb(255, 160, 160), rgb(0, 0, 255), rgb(128, 128, 128)]);
+    /// ```
+    fn perceptual_sort_key(self) -> f32 {
+        let color = self.to_rgb();
+
+        let family = if palette::is_achromatic(color) {
+            12
+        } else {
+            ((color.to_hsl().h.degrees() + 15) / 30) % 12
+        };
+
+        // Keep the lightness strictly below 1.0, so that white stays inside its family.
+        f32::from(family) + color.oklch_lightness().clamp(0.0, 0.999)
+    }
+
+    /// Returns the perceptual distance between `self` and `other`, as the CIE 1976
+    /// color difference (ΔE*ab): the straight-line distance between the two colors
+    /// in the CIELAB color space. A difference of around `2.3` is just noticeable,
+    /// while black and white are `100.0` apart. Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let black = rgb(0, 0, 0);
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert!((black.delta_e(white) - 100.0).abs() < 0.01);
+    /// assert_eq!(white.delta_e(white), 0.0);
+    /// ```
+    fn delta_e<T: Color>(self, other: T) -> f32 {
+        lab::delta_e(self.to_rgb(), other.to_rgb())
+    }
+
+    /// Returns whether `self` and `other` are perceptually close, i.e. whether their
+    /// `delta_e` is at most `threshold`. A `threshold` of `2.3`, the just noticeable
+    /// difference, treats colors the eye cannot tell apart as similar; larger values
+    /// also match colors that are only close. Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsl};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert!(tomato.is_similar(hsl(9, 100, 64), 2.3));
+    /// assert!(tomato.is_similar(rgb(253, 100, 72), 2.3));
+    /// assert!(!tomato.is_similar(rgb(255, 69, 0), 2.3));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_similar<T: Color>(self, other: T, threshold: f32) -> bool {
+        self.delta_e(other) <= threshold
+    }
+
+    /// Returns the CSS color keyword for `self`, if there is a named color whose red,
+    /// green, and blue channels each lie within `tolerance` of `self`. When several
+    /// do, the closest is returned. Any alpha channel is ignored.
+    ///
+    /// This only matches colors that are essentially the same, which makes it useful
+    /// for preferring keywords in generated stylesheets, e.g. with a `tolerance` of `0`
+    /// for exact matches, or `1` to absorb rounding from color conversions.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsl};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).snap_to_named(0), Some("tomato"));
+    /// assert_eq!(hsl(9, 100, 64).snap_to_named(1), Some("tomato"));
+    /// assert_eq!(rgb(250, 100, 70).snap_to_named(1), None);
+    /// ```
+    fn snap_to_named(self, tolerance: u8) -> Option<&'static str> {
+        named::nearest(self.to_rgb(), tolerance)
+    }
+
+    /// Snaps `self` to the nearest color of the 216-color web-safe palette, by rounding
+    /// each channel to the closest of `0`, `51`, `102`, `153`, `204` and `255`.
+    /// Any alpha channel is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).to_web_safe(), rgb(255, 102, 51));
+    /// assert_eq!(rgba(100, 149, 237, 0.5).to_web_safe(), rgb(102, 153, 255));
+    /// ```
+    fn to_web_safe(self) -> RGB {
+        let snap = |channel: Ratio| {
+            let step = (u16::from(channel.as_u8()) + 25) / 51;
+            Ratio::from_u8((step * 51) as u8)
+        };
+        let RGB { r, g, b } = self.to_rgb();
+
+        RGB {
+            r: snap(r),
+            g: snap(g),
+            b: snap(b),
+        }
+    }
+
+    /// Converts `self` into the given color model, and then into its CSS string format.
+    /// The `Rgb` and `Hsl` models drop any alpha channel, while the `Rgba` and `Hsla`
+    /// models treat colors without one as fully opaque. The `Hex` model only includes
+    /// the alpha channel (as `#rrggbbaa`) when `self` is not fully opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, ColorModel, rgb, rgba};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    /// let opaque_tomato = rgba(255, 99, 71, 0.5);
+    ///
+    /// assert_eq!(tomato.to_css_as(ColorModel::Hsl), "hsl(9, 100%, 64%)");
+    /// assert_eq!(tomato.to_css_as(ColorModel::Hex), "#ff6347");
+    /// assert_eq!(opaque_tomato.to_css_as(ColorModel::Hsla), "hsla(9, 100%, 64%, 0.50)");
+    /// assert_eq!(opaque_tomato.to_css_as(ColorModel::Hex), "#ff634780");
+    /// ```
+    fn to_css_as(self, model: ColorModel) -> String {
+        model::to_css_as(self, model)
+    }
+
+    /// Converts `self` to the CSS Color 4 [`color()` function](https://www.w3.org/TR/css-color-4/#color-function)
+    /// in the sRGB color space, with each channel normalized to the `0-1` range and
+    /// rounded to three decimal places. The alpha channel is only included when
+    /// `self` is not fully opaque, with two decimal places as in `rgba()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let tomato = rgb(250, 99, 71);
+    /// let opaque_tomato = rgba(250, 99, 71, 0.25);
+    ///
+    /// assert_eq!(tomato.to_css_color_function(), "color(srgb 0.98 0.388 0.278)");
+    /// assert_eq!(opaque_tomato.to_css_color_function(), "color(srgb 0.98 0.388 0.278 / 0.25)");
+    /// ```
+    fn to_css_color_function(self) -> String {
+        model::color_function(self.to_rgba())
+    }
+
+    /// Converts `self` to the CSS Color 4 [`lab()` function](https://www.w3.org/TR/css-color-4/#specifying-lab-lch),
+    /// giving its CIELAB lightness as a percentage followed by its `a` and `b` axes,
+    /// each rounded to one decimal place. The alpha channel is only included when
+    /// `self` is not fully opaque, with two decimal places as in `rgba()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).to_css_lab(), "lab(62.2% 57.9 46.4)");
+    /// assert_eq!(rgba(255, 255, 255, 0.5).to_css_lab(), "lab(100% 0 0 / 0.50)");
+    /// ```
+    fn to_css_lab(self) -> String {
+        model::lab_function(self.to_rgba())
+    }
+
+    /// Converts `self` to the CSS Color 4 [`lch()` function](https://www.w3.org/TR/css-color-4/#specifying-lab-lch),
+    /// giving its CIELAB lightness as a percentage followed by its chroma and hue angle,
+    /// each rounded to one decimal place. Greys have no hue, and are given a hue of `0`.
+    /// The alpha channel is only included when `self` is not fully opaque, with two
+    /// decimal places as in `rgba()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).to_css_lch(), "lch(62.2% 74.2 38.7)");
+    /// assert_eq!(rgba(0, 0, 0, 0.25).to_css_lch(), "lch(0% 0 0 / 0.25)");
+    /// ```
+    fn to_css_lch(self) -> String {
+        model::lch_function(self.to_rgba())
+    }
+
+    /// Converts `self` to whichever CSS notation gives the shortest string, as CSS
+    /// minifiers do. The candidates are 3, 4, 6 and 8 digit hex, named colors that match
+    /// `self` exactly, and the `rgb()` and `hsl()` functions (with their alpha forms).
+    /// When two notations are the same length, hex is preferred.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 255, 255).to_css_shortest(), "#fff");
+    /// assert_eq!(rgb(255, 0, 0).to_css_shortest(), "red");
+    /// assert_eq!(rgb(210, 180, 140).to_css_shortest(), "tan");
+    /// assert_eq!(rgb(255, 100, 71).to_css_shortest(), "#ff6447");
+    /// assert_eq!(rgba(255, 0, 0, 0.2).to_css_shortest(), "#f003");
+    /// ```
+    fn to_css_shortest(self) -> String {
+        model::shortest(self.to_rgba())
+    }
+
     /// Increases the saturation of `self` by an absolute amount.
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-saturate).
     ///
+    /// For HSL and HSLA colors, `desaturate()` by the same amount undoes this exactly, unless
+    /// the saturation was clamped at 0% or 100%. RGB and RGBA colors are converted to HSL and
+    /// back, which rounds the hue to a whole degree, so the round trip may be off by a few
+    /// units per channel, and by more for colors that end up close to black or white.
+    ///
     /// # Examples
     /// ```
     /// use css_colors::{Color, rgb, hsla, percent};
@@ -105,6 +590,7 @@ pub trait Color {
 
     /// Decreases the saturation of `self` by an absolute amount.
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// The saturation stops at 0%, so `desaturate(percent(100))` is the same as `greyscale()`.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-desaturate).
     ///
     /// # Examples
@@ -123,6 +609,11 @@ pub trait Color {
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-lighten).
     ///
+    /// For HSL and HSLA colors, `darken()` by the same amount undoes this exactly, unless
+    /// the lightness was clamped at 0% or 100%. RGB and RGBA colors are converted to HSL and
+    /// back, which rounds the hue to a whole degree, so the round trip may be off by a few
+    /// units per channel, and by more for colors that end up close to black or white.
+    ///
     /// # Examples
     /// ```
     /// use css_colors::{Color, rgb, rgba, percent};
@@ -135,6 +626,84 @@ pub trait Color {
     /// ```
     fn lighten(self, amount: Ratio) -> Self;
 
+    /// Increases the perceptual lightness of `self` by `amount`, where `amount` is on the
+    /// `0.0-1.0` scale of `lightness()`, and returns the result as RGBA. The chroma, hue and
+    /// alpha channel are kept; if the lighter color no longer fits in sRGB, its chroma is
+    /// reduced until it does. Lightening past `1.0` gives white.
+    ///
+    /// `lighten()` adds to the HSL lightness, which does not match how light a color looks:
+    /// at 50% HSL lightness, a saturated yellow looks far lighter than a saturated blue,
+    /// and lightening each by the same percentage lightens them by different amounts.
+    /// This adjusts the lightness `L` of [OKLab](struct.OKLCH.html) instead, so the same
+    /// `amount` looks equally lighter on every hue, which keeps the steps of light and dark
+    /// theme variants balanced.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let blue = rgb(0, 0, 255);
+    /// let yellow = rgb(128, 128, 0);
+    ///
+    /// let lighter_blue = blue.lighten_oklab(0.1);
+    /// let lighter_yellow = yellow.lighten_oklab(0.1);
+    ///
+    /// assert!((lighter_blue.oklch_lightness() - blue.oklch_lightness() - 0.1).abs() < 0.01);
+    /// assert!((lighter_yellow.oklch_lightness() - yellow.oklch_lightness() - 0.1).abs() < 0.01);
+    /// assert_eq!(rgba(255, 99, 71, 0.5).lighten_oklab(1.0), rgba(255, 255, 255, 0.5));
+    /// ```
+    fn lighten_oklab(self, amount: f32) -> RGBA {
+        oklch::lighten_oklab(self.to_rgba(), amount)
+    }
+
+    /// Decreases the perceptual lightness of `self` by `amount`, the counterpart of
+    /// `lighten_oklab()`. Darkening past `0.0` gives black.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert!((tomato.oklch_lightness() - tomato.darken_oklab(0.2).oklch_lightness() - 0.2).abs() < 0.01);
+    /// assert_eq!(tomato.darken_oklab(1.0), rgb(0, 0, 0).to_rgba());
+    /// ```
+    fn darken_oklab(self, amount: f32) -> RGBA {
+        oklch::lighten_oklab(self.to_rgba(), -amount)
+    }
+
+    /// Increases the lightness of `self` like `lighten()`, but stops at `max_lightness`
+    /// rather than running into pure white. Colors that are already at least as light
+    /// as `max_lightness` are returned unchanged.
+    ///
+    /// Every hue turns into the same white at 100% lightness, so lightening the steps of
+    /// a design-system ramp without a cap makes the lightest steps of different hues
+    /// identical. A cap of `percent(96)` is a good default: light enough to read as
+    /// a near-white tint, while keeping a hint of the hue.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// let salmon = hsl(6, 93, 71);
+    ///
+    /// assert_eq!(salmon.lighten_preserve_hue(percent(10), percent(96)), hsl(6, 93, 81));
+    /// assert_eq!(salmon.lighten_preserve_hue(percent(50), percent(96)), hsl(6, 93, 96));
+    /// assert_eq!(salmon.lighten(percent(50)), hsl(6, 93, 100));
+    /// ```
+    fn lighten_preserve_hue(self, amount: Ratio, max_lightness: Ratio) -> Self
+    where
+        Self: Copy,
+    {
+        let lightness = self.to_hsl().l;
+
+        if lightness >= max_lightness {
+            return self;
+        }
+
+        self.lighten(amount.min(max_lightness - lightness))
+    }
+
     /// Decreases the lightness of `self` by an absolute amount.
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-darken).
@@ -151,6 +720,51 @@ pub trait Color {
     /// ```
     fn darken(self, amount: Ratio) -> Self;
 
+    /// Scales the relative luminance of `self` by `factor`, like the brightness
+    /// slider of a photo editor, rather than shifting its HSL lightness.
+    ///
+    /// The red, green, and blue channels are scaled together in linear-light sRGB,
+    /// which changes the luminance by exactly `factor` while preserving the color's
+    /// chromaticity (its hue and saturation, as seen by the eye). When the result
+    /// would fall outside of the sRGB gamut, it is instead scaled down until its
+    /// brightest channel is at full intensity: this keeps the chromaticity intact
+    /// at the cost of falling short of the requested luminance. Negative factors
+    /// are treated as `0.0`, which yields black.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let dark_red = rgb(128, 0, 0);
+    ///
+    /// assert_eq!(dark_red.brighten_luminance(2.0), rgb(176, 0, 0));
+    /// assert_eq!(dark_red.brighten_luminance(10.0), rgb(255, 0, 0));
+    /// assert_eq!(dark_red.brighten_luminance(0.0), rgb(0, 0, 0));
+    /// ```
+    fn brighten_luminance(self, factor: f32) -> RGB {
+        let RGB { r, g, b } = self.to_rgb();
+        let factor = factor.max(0.0);
+
+        let (r, g, b) = (
+            oklch::to_linear(r.as_f32()) * factor,
+            oklch::to_linear(g.as_f32()) * factor,
+            oklch::to_linear(b.as_f32()) * factor,
+        );
+
+        let brightest = r.max(g).max(b);
+        let scale = if brightest > 1.0 {
+            1.0 / brightest
+        } else {
+            1.0
+        };
+
+        RGB {
+            r: Ratio::clamp(oklch::from_linear(r * scale)),
+            g: Ratio::clamp(oklch::from_linear(g * scale)),
+            b: Ratio::clamp(oklch::from_linear(b * scale)),
+        }
+    }
+
     /// Decreases the transparency (or increase the opacity) of `self`, making it more opaque.
     /// For opqaue colors, converts into the alpha equivalent of `self`, and then increases the opacity.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fadein).
@@ -183,26 +797,88 @@ pub trait Color {
     /// ```
     fn fadeout(self, amount: Ratio) -> Self::Alpha;
 
-    /// Sets the absolute opacity of `self`, and returns the alpha equivalent.
-    /// Can be applied to colors whether they already have an opacity value or not.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fade).
+    /// Same as `fadein()`, but also returns whether the fade reached full opacity,
+    /// including when `amount` would have carried it past that point.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, percent};
+    /// use css_colors::{Color, rgba, percent};
     ///
-    /// let tomato = rgba(255, 99, 71, 0.5);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let tomato = rgba(255, 99, 71, 0.25);
+    ///
+    /// assert_eq!(tomato.fadein_checked(percent(25)), (rgba(255, 99, 71, 0.5), false));
+    /// assert_eq!(tomato.fadein_checked(percent(75)), (rgba(255, 99, 71, 1.0), true));
+    /// ```
+    fn fadein_checked(self, amount: Ratio) -> (Self::Alpha, bool)
+    where
+        Self: Copy,
+    {
+        let saturated = u16::from(self.alpha().as_u8()) + u16::from(amount.as_u8()) >= 255;
+
+        (self.fadein(amount), saturated)
+    }
+
+    /// Same as `fadeout()`, but also returns whether the fade reached full transparency,
+    /// including when `amount` would have carried it past that point.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgba, percent};
+    ///
+    /// let tomato = rgba(255, 99, 71, 0.5);
+    ///
+    /// assert_eq!(tomato.fadeout_checked(percent(25)), (rgba(255, 99, 71, 0.25), false));
+    /// assert_eq!(tomato.fadeout_checked(percent(75)), (rgba(255, 99, 71, 0.0), true));
+    /// ```
+    fn fadeout_checked(self, amount: Ratio) -> (Self::Alpha, bool)
+    where
+        Self: Copy,
+    {
+        let saturated = self.alpha().as_u8() <= amount.as_u8();
+
+        (self.fadeout(amount), saturated)
+    }
+
+    /// Sets the absolute opacity of `self`, and returns the alpha equivalent.
+    /// Can be applied to colors whether they already have an opacity value or not.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fade).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let tomato = rgba(255, 99, 71, 0.5);
+    /// let cornflower_blue = rgb(100, 149, 237);
     ///
     /// assert_eq!(tomato.fade(percent(25)), rgba(255, 99, 71, 0.25));
     /// assert_eq!(cornflower_blue.fade(percent(50)), rgba(100, 149, 237, 0.5));
     /// ```
     fn fade(self, amount: Ratio) -> Self::Alpha;
 
+    /// Sets the absolute opacity of `self` from a raw alpha byte between `0-255`, e.g.
+    /// one decoded from an image. This is the byte-scale counterpart to `fade()`, which
+    /// takes a `Ratio`, and avoids a round trip through a percentage.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, hsla};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).fade_u8(128), rgba(255, 99, 71, 0.5));
+    /// assert_eq!(hsla(9, 100, 64, 1.0).fade_u8(0), hsla(9, 100, 64, 0.0));
+    /// ```
+    fn fade_u8(self, alpha: u8) -> Self::Alpha {
+        self.fade(Ratio::from_u8(alpha))
+    }
+
     /// Rotate the hue angle of `self` in either direction.
-    /// Returns the appropriate `RGB` representation of the color once it has been spun.
+    /// Returns the spun color in the same color model as `self`. For RGB colors, the
+    /// resulting hue can be read back with `RGB::hsl_hue` without a full `to_hsl()`.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-spin).
     ///
+    /// Spinning is always modular: the hue wraps around the color wheel rather than
+    /// overflowing, so `spin(deg(720))` leaves a color unchanged and spinning `10°`
+    /// by `deg(-20)` lands on `350°`.
+    ///
     /// # Examples
     /// ```
     /// use css_colors::{Color, rgb, hsl, deg};
@@ -215,10 +891,75 @@ pub trait Color {
     /// ```
     fn spin(self, amount: Angle) -> Self;
 
+    /// Returns the two colors on either side of the complement of `self`, `150°` and
+    /// `210°` around the color wheel from its hue (the complement, at `180°`, `±30°`).
+    /// Designers often prefer this to a direct complement, which can clash harshly.
+    /// Both colors keep the saturation, lightness and alpha of `self`, and are returned
+    /// in the same color model as `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, hsla};
+    ///
+    /// assert_eq!(hsl(10, 90, 50).split_complementary(), (hsl(160, 90, 50), hsl(220, 90, 50)));
+    /// assert_eq!(
+    ///     hsla(300, 50, 40, 0.5).split_complementary(),
+    ///     (hsla(90, 50, 40, 0.5), hsla(150, 50, 40, 0.5))
+    /// );
+    /// ```
+    fn split_complementary(self) -> (Self, Self)
+    where
+        Self: Copy,
+    {
+        (self.spin(deg(150)), self.spin(deg(210)))
+    }
+
+    /// Sets the hue angle of `self` to `hue`, keeping its saturation, lightness and alpha.
+    /// Unlike `spin`, which rotates the hue relative to its current value, this is an
+    /// absolute setter, so there is no need to work out (and wrap) the difference by hand.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, hsla, deg};
+    ///
+    /// assert_eq!(hsl(6, 93, 71).with_hue(deg(120)), hsl(120, 93, 71));
+    /// assert_eq!(hsla(350, 93, 71, 0.5).with_hue(deg(10)), hsla(10, 93, 71, 0.5));
+    /// ```
+    fn with_hue(self, hue: Angle) -> Self
+    where
+        Self: FromColor,
+    {
+        Self::from_color(self.to_hsla().with_hue(hue))
+    }
+
+    /// Returns whether the HSL hues of `self` and `other` lie within `tolerance`
+    /// of each other, measured along the shorter arc of the color wheel.
+    /// Greys have no hue of their own and are treated as having a hue of `0°`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsl, deg};
+    ///
+    /// let crimson = rgb(220, 20, 60);
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert!(crimson.same_hue_family(tomato, deg(30)));
+    /// assert!(!crimson.same_hue_family(hsl(120, 100, 50), deg(30)));
+    /// ```
+    fn same_hue_family<T: Color>(self, other: T, tolerance: Angle) -> bool {
+        self.to_hsl().h.distance(other.to_hsl().h) <= tolerance
+    }
+
     /// Mixes two colors (`self` and any other `Color`) together in variable proportion.
     /// Takes opacity into account in the calculations.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-mix).
     ///
+    /// The colors are blended in sRGB, which matches Less and Sass but tends to produce
+    /// dull, dark midpoints between saturated colors (e.g. red and green mix to brown).
+    /// Use `mix_oklab()` when the result should look like a natural transition instead,
+    /// or `mix_linear()` for a physically correct blend of light. `mix_in()` takes the
+    /// color space as an argument instead.
+    ///
     /// # Examples
     /// ```
     /// use css_colors::{Color, rgb, rgba, hsl, hsla, percent};
@@ -232,10 +973,119 @@ pub trait Color {
     /// ```
     fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha;
 
+    /// Mixes two colors (`self` and any other `Color`) together in variable proportion,
+    /// interpolating in the perceptual [OKLab](https://bottosson.github.io/posts/oklab/)
+    /// color space, like CSS' `color-mix(in oklab, ...)` does for opaque colors. This avoids
+    /// the muddy midpoints of `mix()`, making it the better choice for gradients and palettes.
+    /// Prefer `mix()` when the result has to match what Less or Sass would produce.
+    ///
+    /// The `weight` is the proportion of `self` in the result. Alpha channels are
+    /// interpolated linearly with the same weight, without taking part in the color blend.
+    /// Unlike `color-mix()`, the colors are not premultiplied by their alpha first, so
+    /// translucent inputs can give a different result than a browser would.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let green = rgb(0, 255, 0);
+    ///
+    /// assert_eq!(red.mix(green, percent(50)), rgba(128, 127, 0, 1.0));
+    /// assert_eq!(red.mix_oklab(green, percent(50)), rgba(208, 168, 0, 1.0));
+    /// ```
+    fn mix_oklab<T: Color>(self, other: T, weight: Ratio) -> RGBA {
+        oklch::mix_oklab(self.to_rgba(), other.to_rgba(), weight.as_f32())
+    }
+
+    /// Mixes the red, green, and blue channels of `self` and `other` by `weight`, like
+    /// `mix()`, but gives the result a fixed `alpha` instead of blending the alpha channels.
+    /// The alpha channels of the inputs are ignored entirely, so they do not skew the
+    /// color blend either. This suits overlays, where the blend should have a set opacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let red = rgba(255, 0, 0, 0.1);
+    /// let blue = rgb(0, 0, 255);
+    ///
+    /// assert_eq!(red.mix_keep_alpha(blue, percent(50), percent(80)), rgba(128, 0, 127, 0.8));
+    /// ```
+    fn mix_keep_alpha<T: Color>(self, other: T, weight: Ratio, alpha: Ratio) -> RGBA {
+        self.to_rgb().mix(other.to_rgb(), weight).fade(alpha)
+    }
+
+    /// Mixes two colors (`self` and any other `Color`) together in variable proportion,
+    /// blending in linear light rather than in gamma-encoded sRGB. This is how light
+    /// physically combines, and fixes the dark band that `mix()` leaves in the middle of
+    /// a gradient between two saturated colors. For opaque colors, it matches CSS'
+    /// `color-mix(in srgb-linear, ...)`.
+    ///
+    /// The `weight` is the proportion of `self` in the result. Alpha channels are
+    /// interpolated linearly with the same weight, without taking part in the color blend,
+    /// so unlike `color-mix()` the colors are not premultiplied by their alpha.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let green = rgb(0, 255, 0);
+    ///
+    /// assert_eq!(red.mix(green, percent(50)), rgba(128, 127, 0, 1.0));
+    /// assert_eq!(red.mix_linear(green, percent(50)), rgba(188, 187, 0, 1.0));
+    /// ```
+    fn mix_linear<T: Color>(self, other: T, weight: Ratio) -> RGBA {
+        oklch::mix_linear(self.to_rgba(), other.to_rgba(), weight.as_f32())
+    }
+
+    /// Mixes two colors (`self` and any other `Color`) together in variable proportion,
+    /// interpolating in the given color `space`, like CSS' `color-mix(in <space>, ...)` does
+    /// for opaque colors. This is a single entry point for the different ways of mixing colors:
+    ///
+    /// - `MixSpace::Srgb` is the same as `mix()`, following Less and Sass.
+    /// - `MixSpace::SrgbLinear` is the same as `mix_linear()`.
+    /// - `MixSpace::Hsl` is the same as `HSLA::mix_hsl()`, taking the shorter arc between hues.
+    /// - `MixSpace::Oklab` is the same as `mix_oklab()`.
+    /// - `MixSpace::Oklch` interpolates the OKLCH lightness, chroma, and hue, taking the
+    ///   shorter arc between hues. This keeps the chroma of the result, where OKLab passes
+    ///   through greyer colors. Results outside of sRGB are gamut-mapped as with
+    ///   `OKLCH::clamp_to_srgb`.
+    ///
+    /// The `weight` is the proportion of `self` in the result. In the hue-based spaces,
+    /// a grey has no meaningful hue, so the other color's hue is kept. Unlike `color-mix()`,
+    /// no space premultiplies the colors by their alpha, so translucent inputs can give a
+    /// different result than a browser would.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsl, percent, MixSpace};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let green = rgb(0, 255, 0);
+    ///
+    /// assert_eq!(red.mix_in(green, percent(50), MixSpace::Srgb), red.mix(green, percent(50)));
+    /// assert_eq!(red.mix_in(green, percent(50), MixSpace::Hsl).to_hsl(), hsl(60, 100, 50));
+    /// assert_eq!(red.mix_in(green, percent(50), MixSpace::Oklab), red.mix_oklab(green, percent(50)));
+    /// ```
+    fn mix_in<T: Color>(self, other: T, weight: Ratio, space: MixSpace) -> RGBA {
+        match space {
+            MixSpace::Srgb => self.to_rgba().mix(other, weight),
+            MixSpace::SrgbLinear => self.mix_linear(other, weight),
+            MixSpace::Hsl => self.to_hsla().mix_hsl(other.to_hsla(), weight).to_rgba(),
+            MixSpace::Oklab => self.mix_oklab(other, weight),
+            MixSpace::Oklch => oklch::mix_oklch(self.to_rgba(), other.to_rgba(), weight.as_f32()),
+        }
+    }
+
     /// Mixes `self` with white in variable proportion.
     /// Equivalent to calling `mix()` with `white` (`rgb(255, 255, 255)`).
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-tint).
     ///
+    /// As with `mix()`, the `weight` is the proportion of `self` that is kept:
+    /// `percent(100)` leaves `self` unchanged, while `percent(0)` yields pure white.
+    ///
     /// # Examples
     /// ```
     /// use css_colors::{Color, rgb, rgba, hsl, percent};
@@ -248,10 +1098,13 @@ pub trait Color {
     /// ```
     fn tint(self, weight: Ratio) -> Self;
 
-    /// Mixes `self` with white in variable proportion.
+    /// Mixes `self` with black in variable proportion.
     /// Equivalent to calling `mix()` with `black` (`rgb(0, 0, 0)`).
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-shade).
     ///
+    /// As with `mix()`, the `weight` is the proportion of `self` that is kept:
+    /// `percent(100)` leaves `self` unchanged, while `percent(0)` yields pure black.
+    ///
     /// # Examples
     /// ```
     /// use css_colors::{Color, rgb, rgba, hsl, percent};
@@ -264,8 +1117,40 @@ pub trait Color {
     /// ```
     fn shade(self, weight: Ratio) -> Self;
 
+    /// Same as `tint()`, but takes the weight as a float between `0.0` and `1.0`.
+    /// Values outside of that range will cause a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, percent};
+    ///
+    /// let golden = rgb(243, 166, 13);
+    ///
+    /// assert_eq!(golden.tint_f32(0.25), golden.tint(percent(25)));
+    /// assert_eq!(golden.tint_f32(0.0), rgb(255, 255, 255));
+    /// ```
+    fn tint_f32(self, weight: f32) -> Self {
+        self.tint(Ratio::from_f32(weight))
+    }
+
+    /// Same as `shade()`, but takes the weight as a float between `0.0` and `1.0`.
+    /// Values outside of that range will cause a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, percent};
+    ///
+    /// let golden = rgb(243, 166, 13);
+    ///
+    /// assert_eq!(golden.shade_f32(0.25), golden.shade(percent(25)));
+    /// assert_eq!(golden.shade_f32(0.0), rgb(0, 0, 0));
+    /// ```
+    fn shade_f32(self, weight: f32) -> Self {
+        self.shade(Ratio::from_f32(weight))
+    }
+
     /// Remove all saturation from `self` in the HSL color space.
-    /// Equivalent to calling `desaturate(0)` on a color.
+    /// Equivalent to calling `desaturate(percent(100))` on a color.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-greyscale).
     ///
     /// # Examples
@@ -279,13 +1164,139 @@ pub trait Color {
     /// assert_eq!(cornflower_blue.greyscale(), rgb(169, 169, 169));
     /// ```
     fn greyscale(self) -> Self;
+
+    /// Moves `self` part of the way toward the grey of the same luminance, where
+    /// `amount` is how far to go: `percent(0)` leaves `self` unchanged, while
+    /// `percent(100)` yields the grey itself. Preserves any existing alpha channel.
+    ///
+    /// Unlike `desaturate()` and `greyscale()`, which keep the HSL lightness, the grey
+    /// here has the same relative luminance as `self`, and the channels are blended
+    /// in RGB. Colors that HSL considers equally light can have very different
+    /// luminances: pure blue fades toward a dark grey and yellow toward a light grey,
+    /// which is closer to how the colors are perceived.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, percent};
+    ///
+    /// let blue = rgb(0, 0, 255);
+    /// let yellow = rgb(255, 255, 0);
+    ///
+    /// assert_eq!(blue.fade_to_gray(percent(100)), rgb(76, 76, 76));
+    /// assert_eq!(yellow.fade_to_gray(percent(100)), rgb(247, 247, 247));
+    /// assert_eq!(blue.greyscale(), yellow.greyscale());
+    /// ```
+    fn fade_to_gray(self, amount: Ratio) -> Self
+    where
+        Self: FromColor,
+    {
+        Self::from_color(self.to_rgba().fade_to_gray(amount))
+    }
+
+    /// Removes all color from `self`, replacing each channel with the weighted sum
+    /// `wr * r + wg * g + wb * b` of the gamma-encoded red, green, and blue channels
+    /// (known as luma). Preserves any existing alpha channel.
+    ///
+    /// The `(wr, wg, wb)` coefficients should sum to `1.0` so that white stays white;
+    /// results outside of the valid range are clamped. `REC709` matches the primaries
+    /// of sRGB and is the usual choice, while `REC601` matches older video standards.
+    /// Unlike `greyscale()`, which keeps the HSL lightness, this keeps the brightness
+    /// of each primary as perceived by the eye.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, REC601, REC709};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.grayscale_weighted(REC709), rgb(130, 130, 130));
+    /// assert_eq!(tomato.grayscale_weighted(REC601), rgb(142, 142, 142));
+    /// ```
+    fn grayscale_weighted(self, coefficients: (f32, f32, f32)) -> Self
+    where
+        Self: FromColor,
+    {
+        Self::from_color(self.to_rgba().grayscale_weighted(coefficients))
+    }
+
+    /// Returns how much `self` can be lightened before its lightness reaches 100%
+    /// and further calls to `lighten()` start clamping.
+    /// Measured within the color's HSL representation.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl};
+    ///
+    /// let red = hsl(10, 90, 70);
+    ///
+    /// assert_eq!(red.lightness_headroom().to_string(), "30%");
+    /// assert_eq!(red.lighten(red.lightness_headroom()), hsl(10, 90, 100));
+    /// ```
+    fn lightness_headroom(self) -> Ratio {
+        percent(100) - self.to_hsl().l
+    }
+
+    /// Returns how much `self` can be darkened before its lightness reaches 0%
+    /// and further calls to `darken()` start clamping.
+    /// Measured within the color's HSL representation.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl};
+    ///
+    /// let red = hsl(10, 90, 70);
+    ///
+    /// assert_eq!(red.lightness_footroom().to_string(), "70%");
+    /// assert_eq!(red.darken(red.lightness_footroom()), hsl(10, 90, 0));
+    /// ```
+    fn lightness_footroom(self) -> Ratio {
+        self.to_hsl().l
+    }
+
+    /// Returns how much `self` can be saturated before its saturation reaches 100%
+    /// and further calls to `saturate()` start clamping.
+    /// Measured within the color's HSL representation.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl};
+    ///
+    /// let red = hsl(10, 90, 70);
+    ///
+    /// assert_eq!(red.saturation_headroom().to_string(), "10%");
+    /// assert_eq!(red.saturate(red.saturation_headroom()), hsl(10, 100, 70));
+    /// ```
+    fn saturation_headroom(self) -> Ratio {
+        percent(100) - self.to_hsl().s
+    }
+
+    /// Returns how much `self` can be desaturated before its saturation reaches 0%
+    /// and further calls to `desaturate()` start clamping.
+    /// Measured within the color's HSL representation.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl};
+    ///
+    /// let red = hsl(10, 90, 70);
+    ///
+    /// assert_eq!(red.saturation_footroom().to_string(), "90%");
+    /// assert_eq!(red.desaturate(red.saturation_footroom()), hsl(10, 0, 70));
+    /// ```
+    fn saturation_footroom(self) -> Ratio {
+        self.to_hsl().s
+    }
 }
 
 #[cfg(test)]
 mod css_color_tests {
     use angle::*;
     use ratio::*;
-    use {hsl, hsla, rgb, rgba, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+    use std::ptr;
+    use {
+        colors_approx_eq, hsl, hsla, hsla_u8, rgb, rgba, rgba_checked, rgba_u8, Angle, Color,
+        ColorError, MixSpace, Ratio, HSL, HSLA, OKLCH, REC601, REC709, RGB, RGBA,
+    };
 
     pub trait ApproximatelyEq {
         fn approximately_eq(self, other: Self) -> bool;
@@ -603,6 +1614,47 @@ mod css_color_tests {
         );
     }
 
+    #[test]
+    fn fully_desaturating_matches_greyscale() {
+        let colors = [
+            rgb(0, 0, 0),
+            rgb(230, 230, 230),
+            rgb(255, 255, 255),
+            rgb(253, 216, 229),
+            rgb(172, 96, 83),
+            rgb(23, 98, 119),
+            rgb(89, 161, 54),
+            rgb(148, 189, 209),
+            rgb(136, 102, 153),
+            rgb(230, 25, 60),
+            rgb(255, 99, 71),
+            rgb(255, 160, 122),
+            rgb(138, 43, 226),
+            rgb(255, 140, 0),
+            rgb(255, 20, 147),
+            rgb(127, 255, 0),
+        ];
+
+        for &color in &colors {
+            let transparent = color.fade(percent(78));
+
+            assert_eq!(color.desaturate(percent(100)), color.greyscale());
+            assert_eq!(
+                transparent.desaturate(percent(100)),
+                transparent.greyscale()
+            );
+            assert_eq!(
+                color.to_hsl().desaturate(percent(100)),
+                color.to_hsl().greyscale()
+            );
+            assert_eq!(
+                transparent.to_hsla().desaturate(percent(100)),
+                transparent.to_hsla().greyscale()
+            );
+            assert_eq!(color.to_hsl().desaturate(percent(100)).s, percent(0));
+        }
+    }
+
     #[test]
     fn can_lighten() {
         assert_approximately_eq!(hsl(9, 35, 50).lighten(percent(20)), hsl(9, 35, 70));
@@ -662,6 +1714,19 @@ mod css_color_tests {
         );
     }
 
+    #[test]
+    fn can_fade_u8() {
+        let tomato = rgb(255, 99, 71);
+        let faded = tomato.fade_u8(128);
+
+        assert_eq!(faded.a.as_u8(), 128);
+        assert!((faded.a.as_f32() - 0.5).abs() < 0.01);
+        assert_eq!(faded, tomato.fade(Ratio::from_u8(128)));
+        assert_eq!(tomato.fade_u8(255), rgba(255, 99, 71, 1.0));
+        assert_eq!(rgba(255, 99, 71, 0.5).fade_u8(51).a, percent(20));
+        assert_eq!(hsl(9, 100, 64).fade_u8(0), hsla(9, 100, 64, 0.0));
+    }
+
     #[test]
     fn can_fade() {
         let faded_color = rgba(23, 98, 119, 0.5);
@@ -686,6 +1751,35 @@ mod css_color_tests {
         assert_approximately_eq!(hsla(10, 90, 50, 1.0).spin(deg(30)), hsla(40, 90, 50, 1.0));
     }
 
+    #[test]
+    fn can_spin_past_a_full_turn() {
+        let salmon = hsl(6, 93, 71);
+
+        assert_eq!(salmon.spin(deg(360)), salmon);
+        assert_eq!(salmon.spin(deg(720)), salmon);
+        assert_eq!(salmon.spin(deg(-720)), salmon);
+        assert_eq!(salmon.spin(deg(-10)), hsl(356, 93, 71));
+        assert_eq!(salmon.spin(deg(-370)), hsl(356, 93, 71));
+        assert_eq!(salmon.spin(deg(1000)), hsl(286, 93, 71));
+        assert_eq!(salmon.spin(deg_precise(-3610.4)), hsl(356, 93, 71));
+
+        // Adding the largest legal angles still wraps around.
+        let almost_full_turn = Angle::new(359);
+        assert_eq!(salmon.spin(almost_full_turn), hsl(5, 93, 71));
+        assert_eq!(
+            salmon.spin(almost_full_turn).spin(almost_full_turn),
+            hsl(4, 93, 71)
+        );
+        assert_eq!(
+            hsla(359, 93, 71, 0.5).spin(almost_full_turn),
+            hsla(358, 93, 71, 0.5)
+        );
+
+        let tomato = rgb(255, 99, 71);
+        assert_eq!(tomato.spin(deg(720)), tomato.spin(deg(0)));
+        assert_eq!(tomato.spin(deg(-350)), tomato.spin(deg(10)));
+    }
+
     #[test]
     fn can_spin_backwards() {
         assert_approximately_eq!(rgb(75, 207, 23).spin(deg(-100)), rgb(207, 32, 23));
@@ -753,42 +1847,1009 @@ mod css_color_tests {
     }
 
     #[test]
-    fn can_tint() {
-        assert_approximately_eq!(
-            rgba(0, 0, 255, 0.5).tint(percent(50)),
-            rgba(191, 191, 255, 0.75)
+    fn can_read_alpha() {
+        assert_eq!(rgb(255, 99, 71).alpha(), percent(100));
+        assert_eq!(hsl(9, 100, 64).alpha(), percent(100));
+        assert_eq!(rgba(255, 99, 71, 0.5).alpha(), Ratio::from_f32(0.5));
+        assert_eq!(hsla(9, 100, 64, 0.25).alpha(), Ratio::from_f32(0.25));
+    }
+
+    #[test]
+    fn tint_and_shade_keep_self_at_full_weight() {
+        let golden = rgb(243, 166, 13);
+
+        assert_eq!(golden.tint(percent(100)), golden);
+        assert_eq!(golden.tint(percent(0)), rgb(255, 255, 255));
+        assert_eq!(golden.shade(percent(100)), golden);
+        assert_eq!(golden.shade(percent(0)), rgb(0, 0, 0));
+
+        assert_eq!(golden.tint_f32(1.0), golden);
+        assert_eq!(golden.tint_f32(0.0), rgb(255, 255, 255));
+        assert_eq!(golden.shade_f32(1.0), golden);
+        assert_eq!(golden.shade_f32(0.0), rgb(0, 0, 0));
+        assert_eq!(golden.tint_f32(0.1), golden.tint(percent(10)));
+        assert_eq!(
+            hsl(10, 90, 50).shade_f32(0.1),
+            hsl(10, 90, 50).shade(percent(10))
         );
-        assert_approximately_eq!(rgb(0, 0, 255).tint(percent(50)), rgb(128, 128, 255));
-        assert_approximately_eq!(hsl(6, 93, 71).tint(percent(50)), hsl(6, 92, 85));
-        assert_approximately_eq!(
-            hsla(6, 93, 71, 0.5).tint(percent(50)),
-            hsla(6, 95, 93, 0.75)
+    }
+
+    #[test]
+    fn can_detect_fade_boundaries() {
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert!(!tomato.fadein_checked(percent(25)).1);
+        assert!(tomato.fadein_checked(percent(50)).1);
+        assert!(tomato.fadein_checked(percent(100)).1);
+        assert!(!tomato.fadeout_checked(percent(25)).1);
+        assert!(tomato.fadeout_checked(percent(50)).1);
+        assert!(tomato.fadeout_checked(percent(100)).1);
+
+        // Opaque colors are already at full opacity.
+        assert_eq!(
+            rgb(255, 99, 71).fadein_checked(percent(0)),
+            (rgba(255, 99, 71, 1.0), true)
+        );
+        assert_eq!(
+            hsl(9, 100, 64).fadeout_checked(percent(100)),
+            (hsla(9, 100, 64, 0.0), true)
         );
     }
 
     #[test]
-    fn can_shade() {
-        assert_approximately_eq!(
-            rgba(0, 0, 255, 0.5).shade(percent(50)),
-            rgba(0, 0, 64, 0.75)
+    fn can_try_new_hsl() {
+        assert_eq!(HSL::try_new(370, 100, 0), Ok(hsl(10, 100, 0)));
+        assert_eq!(
+            HSL::try_new(10, 101, 50),
+            Err(ColorError::InvalidPercentage(101))
         );
-        assert_approximately_eq!(rgb(0, 0, 255).shade(percent(50)), rgb(0, 0, 128));
-        assert_approximately_eq!(hsl(6, 93, 71).shade(percent(50)), hsl(6, 38, 36));
-        assert_approximately_eq!(
-            hsla(6, 93, 71, 0.5).shade(percent(50)),
-            hsla(7, 38, 18, 0.75)
+        assert_eq!(
+            HSL::try_new(10, 50, 255),
+            Err(ColorError::InvalidPercentage(255))
+        );
+
+        assert_eq!(HSLA::try_new(-10, 0, 100, 0.0), Ok(hsla(350, 0, 100, 0.0)));
+        assert_eq!(
+            HSLA::try_new(10, 150, 50, 0.5),
+            Err(ColorError::InvalidPercentage(150))
+        );
+        assert_eq!(
+            HSLA::try_new(10, 50, 50, -0.5),
+            Err(ColorError::InvalidAlpha(-0.5))
         );
     }
 
     #[test]
-    fn can_greyscale() {
-        assert_approximately_eq!(rgb(128, 242, 13).greyscale(), rgb(128, 128, 128));
-        assert_approximately_eq!(
-            rgba(128, 242, 13, 1.0).greyscale(),
-            rgba(128, 128, 128, 1.0)
+    fn can_check_rgba_alpha() {
+        assert_eq!(rgba_checked(255, 99, 71, 0.0), Ok(rgba(255, 99, 71, 0.0)));
+        assert_eq!(rgba_checked(255, 99, 71, 1.0), Ok(rgba(255, 99, 71, 1.0)));
+        assert_eq!(
+            rgba_checked(255, 99, 71, 1.000_001),
+            Err(ColorError::InvalidAlpha(1.000_001))
         );
-        assert_approximately_eq!(hsl(90, 90, 50).greyscale(), hsl(90, 0, 50));
-        assert_approximately_eq!(hsla(90, 90, 50, 1.0).greyscale(), hsla(90, 0, 50, 1.0));
+        assert_eq!(
+            rgba_checked(255, 99, 71, -0.5),
+            Err(ColorError::InvalidAlpha(-0.5))
+        );
+        assert!(rgba_checked(255, 99, 71, f32::NAN).is_err());
+    }
+
+    #[test]
+    fn can_brighten_luminance() {
+        let tomato = rgb(255, 99, 71);
+        let grey = rgb(100, 100, 100);
+
+        assert_eq!(grey.brighten_luminance(1.0), grey);
+        assert_eq!(grey.brighten_luminance(-1.0), rgb(0, 0, 0));
+        assert_eq!(rgb(0, 0, 0).brighten_luminance(5.0), rgb(0, 0, 0));
+
+        // Greys stay grey, rather than picking up a tint.
+        let brighter = grey.brighten_luminance(2.0);
+        assert!(brighter.r == brighter.g && brighter.g == brighter.b);
+        assert!(brighter.r > grey.r);
+
+        // Out-of-gamut results are capped without changing the hue.
+        let brick = rgb(200, 80, 60);
+        let capped = brick.brighten_luminance(4.0);
+        assert_eq!(capped.r, percent(100));
+        assert!(capped.g < percent(100) && capped.b < percent(100));
+        assert_approximately_eq!(capped.to_hsl().h, brick.to_hsl().h);
+
+        let dimmed = hsl(9, 100, 64).brighten_luminance(0.5);
+        assert!(dimmed.to_hsl().l < tomato.to_hsl().l);
+        assert_approximately_eq!(dimmed.to_hsl().h, tomato.to_hsl().h);
+    }
+
+    #[test]
+    fn can_match_hue_families() {
+        assert!(hsl(10, 100, 50).same_hue_family(hsl(20, 50, 20), deg(10)));
+        assert!(!hsl(10, 100, 50).same_hue_family(hsl(21, 50, 20), deg(10)));
+
+        // Hues on either side of red are compared across the 0/360 boundary.
+        assert!(hsl(355, 100, 50).same_hue_family(hsl(5, 100, 50), deg(10)));
+        assert!(hsl(5, 100, 50).same_hue_family(hsla(355, 100, 50, 0.5), deg(10)));
+        assert!(!hsl(350, 100, 50).same_hue_family(hsl(10, 100, 50), deg(10)));
+
+        assert!(rgb(255, 0, 0).same_hue_family(rgb(0, 255, 255), deg(180)));
+        assert!(!rgb(255, 0, 0).same_hue_family(rgb(0, 255, 255), deg(179)));
+    }
+
+    #[test]
+    fn can_replace_single_channels() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(tomato.with_red(0), rgb(0, 99, 71));
+        assert_eq!(tomato.with_green(0), rgb(255, 0, 71));
+        assert_eq!(tomato.with_blue(0), rgb(255, 99, 0));
+
+        let tomato = rgba(255, 99, 71, 1.0);
+
+        assert_eq!(tomato.with_red(0), rgba(0, 99, 71, 1.0));
+        assert_eq!(tomato.with_green(0), rgba(255, 0, 71, 1.0));
+        assert_eq!(tomato.with_blue(0), rgba(255, 99, 0, 1.0));
+        assert_eq!(tomato.with_alpha_u8(0), rgba(255, 99, 71, 0.0));
+
+        let salmon = hsla(6, 93, 71, 0.5);
+
+        assert_eq!(salmon.with_hue(deg(180)), hsla(180, 93, 71, 0.5));
+        assert_eq!(salmon.with_saturation(percent(0)), hsla(6, 0, 71, 0.5));
+        assert_eq!(salmon.with_lightness(percent(100)), hsla(6, 93, 100, 0.5));
+        assert_eq!(salmon.to_hsl().with_saturation(percent(50)), hsl(6, 50, 71));
+        assert_eq!(salmon.to_hsl().with_lightness(percent(50)), hsl(6, 93, 50));
+    }
+
+    #[test]
+    fn inverse_operations_cancel_out_in_hsl() {
+        for h in (0..360).step_by(30) {
+            for s in (0..=100).step_by(10) {
+                for l in (0..=100).step_by(10) {
+                    let color = hsla(h, s, l, 1.0);
+
+                    for &amount in &[1, 5, 20, 50] {
+                        let amount = percent(amount);
+
+                        if color.s.as_u8() as u16 + amount.as_u8() as u16 <= 255 {
+                            assert_eq!(color.saturate(amount).desaturate(amount), color);
+                        }
+
+                        if color.l.as_u8() as u16 + amount.as_u8() as u16 <= 255 {
+                            assert_eq!(color.lighten(amount).darken(amount), color);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_operations_nearly_cancel_out_in_rgb() {
+        let distance = |lhs: RGB, rhs: RGB| {
+            [(lhs.r, rhs.r), (lhs.g, rhs.g), (lhs.b, rhs.b)]
+                .iter()
+                .map(|&(lhs, rhs)| (i16::from(lhs.as_u8()) - i16::from(rhs.as_u8())).abs())
+                .max()
+                .unwrap()
+        };
+
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+                    let HSL { s, l, .. } = color.to_hsl();
+
+                    for &amount in &[5, 10, 20] {
+                        let amount = percent(amount);
+
+                        if s.as_u8() as u16 + amount.as_u8() as u16 <= 255 {
+                            let round_trip = color.saturate(amount).desaturate(amount);
+                            assert!(distance(round_trip, color) <= 5, "{}", color);
+                        }
+
+                        if l >= percent(20) && l + amount <= percent(80) {
+                            let round_trip = color.lighten(amount).darken(amount);
+                            assert!(distance(round_trip, color) <= 5, "{}", color);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn converts_fully_saturated_colors_to_hsl() {
+        // Floating point error used to push the saturation past 100% and panic.
+        assert_eq!(rgb(17, 17, 255).to_hsl().s, percent(100));
+        assert_eq!(rgb(0, 0, 15).lighten(percent(50)).to_hsl().s, percent(100));
+    }
+
+    #[test]
+    fn can_read_hsl_hue_from_rgb() {
+        assert_eq!(rgb(255, 0, 0).hsl_hue(), deg(0));
+        assert_eq!(rgb(255, 255, 0).hsl_hue(), deg(60));
+        assert_eq!(rgb(0, 255, 0).hsl_hue(), deg(120));
+        assert_eq!(rgb(0, 255, 255).hsl_hue(), deg(180));
+        assert_eq!(rgb(0, 0, 255).hsl_hue(), deg(240));
+        assert_eq!(rgb(255, 0, 255).hsl_hue(), deg(300));
+        assert_eq!(rgba(255, 99, 71, 0.5).hsl_hue(), deg(9));
+
+        // Achromatic colors have no hue.
+        assert_eq!(rgb(0, 0, 0).hsl_hue(), deg(0));
+        assert_eq!(rgb(128, 128, 128).hsl_hue(), deg(0));
+        assert_eq!(rgb(255, 255, 255).hsl_hue(), deg(0));
+        assert_eq!(rgb(255, 255, 255).hsl_hue_precise(), 0.0);
+    }
+
+    #[test]
+    fn can_read_precise_hsl_hue_from_rgb() {
+        assert_eq!(rgb(255, 0, 0).hsl_hue_precise(), 0.0);
+        assert_eq!(rgb(0, 0, 255).hsl_hue_precise(), 240.0);
+        assert_eq!(format!("{:.2}", rgb(255, 99, 71).hsl_hue_precise()), "9.13");
+        assert_eq!(
+            format!("{:.2}", rgb(255, 0, 128).hsl_hue_precise()),
+            "329.88"
+        );
+        assert_eq!(rgb(255, 0, 128).hsl_hue(), deg(330));
+
+        for color in &[rgb(255, 99, 71), rgb(17, 17, 255), rgb(90, 200, 10)] {
+            assert_eq!(color.hsl_hue(), color.to_hsl().h);
+        }
+    }
+
+    #[test]
+    fn can_sweep_hues() {
+        assert_eq!(HSL::hue_sweep(percent(100), percent(50), 0), []);
+        assert_eq!(
+            HSL::hue_sweep(percent(100), percent(50), 1),
+            [hsl(0, 100, 50)]
+        );
+        assert_eq!(
+            HSL::hue_sweep(percent(80), percent(40), 4),
+            [
+                hsl(0, 80, 40),
+                hsl(90, 80, 40),
+                hsl(180, 80, 40),
+                hsl(270, 80, 40)
+            ]
+        );
+
+        let sweep = HSL::hue_sweep(percent(100), percent(50), 7);
+        let hues: Vec<u16> = sweep.iter().map(|color| color.h.degrees()).collect();
+
+        assert_eq!(hues, [0, 51, 103, 154, 206, 257, 309]);
+    }
+
+    #[test]
+    fn can_fade_to_gray() {
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert_eq!(tomato.fade_to_gray(percent(0)), tomato);
+        assert_eq!(tomato.fade_to_gray(percent(100)), rgba(150, 150, 150, 0.5));
+        assert_eq!(tomato.fade_to_gray(percent(50)), rgba(202, 125, 111, 0.5));
+
+        assert_eq!(rgb(0, 0, 0).fade_to_gray(percent(50)), rgb(0, 0, 0));
+        assert_eq!(
+            rgb(128, 128, 128).fade_to_gray(percent(100)),
+            rgb(128, 128, 128)
+        );
+        assert_approximately_eq!(
+            hsl(9, 100, 64).fade_to_gray(percent(50)),
+            rgb(255, 99, 71).fade_to_gray(percent(50)).to_hsl()
+        );
+        assert_eq!(hsla(9, 100, 64, 0.5).fade_to_gray(percent(100)).a, tomato.a);
+    }
+
+    #[test]
+    fn can_construct_with_u8_alpha() {
+        assert_eq!(rgba_u8(255, 99, 71, 255), rgba(255, 99, 71, 1.0));
+        assert_eq!(rgba_u8(255, 99, 71, 128), rgba(255, 99, 71, 0.5));
+        assert_eq!(rgba_u8(255, 99, 71, 0).a, percent(0));
+
+        assert_eq!(hsla_u8(9, 100, 64, 255), hsla(9, 100, 64, 1.0));
+        assert_eq!(hsla_u8(369, 100, 64, 128), hsla(9, 100, 64, 0.5));
+        assert_eq!(hsla_u8(9, 100, 64, 0).a, percent(0));
+    }
+
+    #[test]
+    fn rgb_round_trips_through_rgba() {
+        for r in 0..=255 {
+            for g in 0..=255 {
+                for b in 0..=255 {
+                    let color = rgb(r, g, b);
+
+                    assert_eq!(color.to_rgba().to_rgb(), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rgba_loses_alpha_through_rgb() {
+        assert_eq!(
+            rgba(255, 99, 71, 0.5).to_rgb().to_rgba(),
+            rgba(255, 99, 71, 1.0)
+        );
+        assert_eq!(
+            rgba(255, 99, 71, 0.0).to_rgb().to_rgba(),
+            rgba(255, 99, 71, 1.0)
+        );
+        assert_eq!(
+            rgba(255, 99, 71, 1.0).to_rgb().to_rgba(),
+            rgba(255, 99, 71, 1.0)
+        );
+    }
+
+    #[test]
+    fn hsl_operations_match_hsla() {
+        for h in (0..360).step_by(45) {
+            for s in (0..=100).step_by(25) {
+                for l in (0..=100).step_by(25) {
+                    let color = hsl(h, s, l);
+                    let alpha = color.to_hsla();
+
+                    for &amount in &[0, 10, 50, 100] {
+                        let amount = percent(amount);
+
+                        assert_eq!(color.saturate(amount), alpha.saturate(amount).to_hsl());
+                        assert_eq!(color.desaturate(amount), alpha.desaturate(amount).to_hsl());
+                        assert_eq!(color.lighten(amount), alpha.lighten(amount).to_hsl());
+                        assert_eq!(color.darken(amount), alpha.darken(amount).to_hsl());
+                    }
+
+                    for &amount in &[0, 90, 359] {
+                        assert_eq!(color.spin(deg(amount)), alpha.spin(deg(amount)).to_hsl());
+                    }
+
+                    assert_eq!(color.greyscale(), alpha.greyscale().to_hsl());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_grayscale_with_weights() {
+        assert_eq!(rgb(255, 0, 0).grayscale_weighted(REC601), rgb(76, 76, 76));
+        assert_eq!(
+            rgb(0, 255, 0).grayscale_weighted(REC709),
+            rgb(182, 182, 182)
+        );
+        assert_eq!(
+            rgb(255, 255, 255).grayscale_weighted(REC601),
+            rgb(255, 255, 255)
+        );
+        assert_eq!(
+            rgba(0, 0, 255, 0.5).grayscale_weighted((0.0, 0.0, 1.0)),
+            rgba(255, 255, 255, 0.5)
+        );
+        assert_eq!(
+            rgb(255, 255, 255).grayscale_weighted((1.0, 1.0, 1.0)),
+            rgb(255, 255, 255)
+        );
+        assert_eq!(
+            hsla(0, 100, 50, 0.5).grayscale_weighted(REC601),
+            hsla(0, 0, 30, 0.5)
+        );
+    }
+
+    #[test]
+    fn can_check_opacity() {
+        assert!(rgb(0, 0, 0).is_opaque());
+        assert!(hsl(0, 0, 0).is_opaque());
+        assert!(!rgb(0, 0, 0).is_transparent());
+        assert!(!hsl(0, 0, 0).is_translucent());
+
+        assert!(rgba_u8(0, 0, 0, 0).is_transparent());
+        assert!(rgba_u8(0, 0, 0, 1).is_translucent());
+        assert!(rgba_u8(0, 0, 0, 254).is_translucent());
+        assert!(rgba_u8(0, 0, 0, 255).is_opaque());
+
+        assert!(hsla(0, 0, 0, 0.0).is_transparent());
+        assert!(hsla(0, 0, 0, 0.5).is_translucent());
+        assert!(hsla(0, 0, 0, 1.0).is_opaque());
+    }
+
+    #[test]
+    fn can_compare_colors_perceptually() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(tomato.delta_e(tomato), 0.0);
+        assert_eq!(
+            tomato.delta_e(rgb(0, 0, 255)),
+            rgb(0, 0, 255).delta_e(tomato)
+        );
+        assert_eq!(tomato.delta_e(rgba(255, 99, 71, 0.0)), 0.0);
+
+        assert!(tomato.is_similar(tomato, 0.0));
+        assert!(tomato.is_similar(tomato.to_hsla(), 2.3));
+        assert!(!tomato.is_similar(rgb(0, 0, 255), 2.3));
+        assert!(tomato.is_similar(rgb(0, 0, 255), 200.0));
+
+        // Equal steps in RGB are not equal steps to the eye.
+        let dark_step = rgb(0, 0, 10).delta_e(rgb(0, 0, 20));
+        let light_step = rgb(0, 0, 235).delta_e(rgb(0, 0, 245));
+        assert!(dark_step != light_step);
+    }
+
+    #[test]
+    fn can_set_hue() {
+        let salmon = hsl(6, 93, 71);
+
+        assert_eq!(salmon.with_hue(deg(200)), salmon.spin(deg(194)));
+        assert_eq!(salmon.with_hue(deg(6)), salmon);
+        assert_eq!(salmon.with_hue(deg(360 + 60)), hsl(60, 93, 71));
+
+        assert_eq!(
+            rgb(0, 255, 0).with_hue(deg(0)),
+            rgb(0, 255, 0).spin(deg(-120))
+        );
+        assert_eq!(
+            rgba(255, 0, 0, 0.5).with_hue(deg(120)),
+            rgba(255, 0, 0, 0.5).spin(deg(120))
+        );
+
+        // Greys have no hue to replace.
+        assert_eq!(rgb(128, 128, 128).with_hue(deg(90)), rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn can_mix_linear() {
+        let red = rgb(255, 0, 0);
+        let green = rgb(0, 255, 0);
+
+        let srgb_midpoint = red.mix(green, percent(50));
+        let linear_midpoint = red.mix_linear(green, percent(50));
+
+        // Blending in linear light avoids the dark band of an sRGB blend.
+        assert!(linear_midpoint.luminance() > srgb_midpoint.luminance());
+        assert!(linear_midpoint.r > srgb_midpoint.r && linear_midpoint.g > srgb_midpoint.g);
+
+        assert_eq!(red.mix_linear(green, percent(100)), red.to_rgba());
+        assert_eq!(red.mix_linear(green, percent(0)), green.to_rgba());
+        assert_eq!(
+            rgb(10, 20, 30).mix_linear(rgb(10, 20, 30), percent(30)),
+            rgba(10, 20, 30, 1.0)
+        );
+        assert_eq!(
+            rgba(255, 0, 0, 1.0)
+                .mix_linear(rgba(0, 0, 0, 0.0), percent(50))
+                .a
+                .as_percentage(),
+            50
+        );
+    }
+
+    #[test]
+    fn can_pick_best_background() {
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+        let backgrounds = [rgb(255, 99, 71), rgb(0, 0, 128), rgb(240, 240, 240)];
+
+        assert_eq!(white.best_background(&backgrounds), Some(&backgrounds[1]));
+        assert_eq!(black.best_background(&backgrounds), Some(&backgrounds[2]));
+        assert_eq!(
+            rgba(255, 255, 255, 0.0).best_background(&backgrounds),
+            Some(&backgrounds[1])
+        );
+        assert_eq!(black.best_background(&[]), None);
+        assert_eq!(
+            black.best_background(&backgrounds[..1]),
+            Some(&backgrounds[0])
+        );
+
+        // Ties go to the earliest candidate.
+        let ties = [rgb(10, 10, 10), white, rgb(255, 255, 255)];
+        assert!(ptr::eq(black.best_background(&ties).unwrap(), &ties[1]));
+    }
+
+    #[test]
+    fn can_snap_to_web_safe() {
+        // Channels round to the nearest multiple of 51, on either side of each boundary.
+        assert_eq!(rgb(0, 25, 26).to_web_safe(), rgb(0, 0, 51));
+        assert_eq!(rgb(76, 77, 127).to_web_safe(), rgb(51, 102, 102));
+        assert_eq!(rgb(128, 178, 179).to_web_safe(), rgb(153, 153, 204));
+        assert_eq!(rgb(229, 230, 255).to_web_safe(), rgb(204, 255, 255));
+
+        let web_safe = rgb(51, 204, 153);
+        assert_eq!(web_safe.to_web_safe(), web_safe);
+        assert_eq!(web_safe.to_hsl().to_web_safe(), web_safe);
+        assert_eq!(rgba(50, 205, 150, 0.0).to_web_safe(), web_safe);
+    }
+
+    #[test]
+    fn can_read_normalized_channels() {
+        let [r, g, b, a] = rgb(255, 99, 71).channels_f32();
+        assert_eq!((r, a), (1.0, 1.0));
+        assert_eq!((g * 255.0).round(), 99.0);
+        assert_eq!((b * 255.0).round(), 71.0);
+
+        let [_, _, _, a] = rgba(255, 99, 71, 0.5).channels_f32();
+        assert_eq!((a * 255.0).round(), 128.0);
+
+        assert_eq!(
+            hsla(9, 100, 64, 0.5).channels_f32(),
+            hsla(9, 100, 64, 0.5).to_rgba().channels_f32()
+        );
+    }
+
+    #[test]
+    fn can_read_ansi_256_colors() {
+        assert_eq!(RGB::from_ansi_256(0), rgb(0, 0, 0));
+        assert_eq!(RGB::from_ansi_256(12), rgb(92, 92, 255));
+        assert_eq!(RGB::from_ansi_256(15), rgb(255, 255, 255));
+        assert_eq!(RGB::from_ansi_256(231), rgb(255, 255, 255));
+        assert_eq!(RGB::from_ansi_256(244), rgb(128, 128, 128));
+
+        // Every cube index maps back to itself through the cube's channel levels.
+        let levels = [0, 95, 135, 175, 215, 255];
+        let level = |channel: Ratio| levels.iter().position(|&l| l == channel.as_u8()).unwrap();
+
+        for index in 16..=231 {
+            let color = RGB::from_ansi_256(index);
+            let cube = 36 * level(color.r) + 6 * level(color.g) + level(color.b);
+
+            assert_eq!(cube + 16, usize::from(index));
+        }
+    }
+
+    #[test]
+    fn can_split_complementary() {
+        let red = rgb(255, 0, 0);
+        let (left, right) = red.split_complementary();
+        assert_eq!((left, right), (red.spin(deg(150)), red.spin(deg(-150))));
+        assert_eq!((left.hsl_hue(), right.hsl_hue()), (deg(150), deg(210)));
+
+        let translucent = rgba(255, 0, 0, 0.5);
+        let (left, right) = translucent.split_complementary();
+        assert_eq!((left.a, right.a), (translucent.a, translucent.a));
+        assert_eq!(right.hsl_hue(), deg(210));
+
+        // The hue wraps around the color wheel.
+        assert_eq!(
+            hsl(200, 60, 30).split_complementary(),
+            (hsl(350, 60, 30), hsl(50, 60, 30))
+        );
+    }
+
+    #[test]
+    fn can_compare_colors_with_a_tolerance() {
+        let tomato = rgb(255, 99, 71);
+
+        assert!(colors_approx_eq(tomato, tomato, 0));
+        assert!(!colors_approx_eq(tomato, rgb(255, 98, 71), 0));
+        assert!(colors_approx_eq(tomato, rgb(250, 104, 66), 5));
+        assert!(!colors_approx_eq(tomato, rgb(250, 104, 65), 5));
+
+        // The alpha channel is compared too, and opaque types have an alpha of 255.
+        assert!(colors_approx_eq(tomato, rgba(255, 99, 71, 0.99), 3));
+        assert!(!colors_approx_eq(tomato, rgba(255, 99, 71, 0.9), 3));
+        assert!(colors_approx_eq(
+            hsla(9, 100, 64, 0.5),
+            rgba(255, 99, 71, 0.5),
+            1
+        ));
+    }
+
+    #[test]
+    fn can_composite_onto_a_background() {
+        let bg = rgb(0, 0, 255);
+
+        assert_eq!(rgba(255, 0, 0, 0.0).composite_onto(bg), bg);
+        assert_eq!(rgba(255, 0, 0, 0.5).composite_onto(bg), rgb(128, 0, 127));
+        assert_eq!(rgba(255, 0, 0, 1.0).composite_onto(bg), rgb(255, 0, 0));
+
+        // Matches stacking onto the opaque background.
+        let fg = rgba(255, 99, 71, 0.25);
+        assert_eq!(fg.composite_onto(bg), bg.to_rgba().stack(fg).to_rgb());
+    }
+
+    #[test]
+    fn can_stack_layers() {
+        let translucent_blue = rgba(0, 0, 255, 0.5);
+
+        // An opaque layer hides whatever is underneath it.
+        assert_eq!(
+            translucent_blue.stack(rgba(255, 99, 71, 1.0)),
+            rgba(255, 99, 71, 1.0)
+        );
+
+        // An opaque base stays opaque, whatever is stacked over it.
+        assert_eq!(
+            rgba(0, 0, 255, 1.0).stack(rgba(255, 0, 0, 0.5)),
+            rgba(128, 0, 127, 1.0)
+        );
+
+        let stacked = translucent_blue.stack(rgba(255, 0, 0, 0.5));
+        assert_eq!(
+            (stacked.r, stacked.g, stacked.b),
+            (Ratio::from_u8(170), Ratio::from_u8(0), Ratio::from_u8(85))
+        );
+        assert_eq!(stacked.a.as_percentage(), 75);
+
+        // Fully transparent layers leave the other untouched.
+        assert_eq!(
+            translucent_blue.stack(rgba(255, 0, 0, 0.0)),
+            translucent_blue
+        );
+        assert_eq!(
+            rgba(255, 0, 0, 0.0).stack(translucent_blue),
+            translucent_blue
+        );
+        assert_eq!(
+            rgba(255, 0, 0, 0.0).stack(rgba(0, 255, 0, 0.0)),
+            rgba(0, 0, 0, 0.0)
+        );
+    }
+
+    #[test]
+    fn can_mix_with_fixed_alpha() {
+        let red = rgb(255, 0, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(
+            red.mix_keep_alpha(blue, percent(50), percent(25)),
+            red.mix(blue, percent(50)).fade(percent(25))
+        );
+        assert_eq!(
+            red.mix_keep_alpha(blue, percent(100), percent(0)),
+            rgba(255, 0, 0, 0.0)
+        );
+
+        // The alphas of the inputs affect neither the color nor the alpha of the result.
+        assert_eq!(
+            rgba(255, 0, 0, 0.0).mix_keep_alpha(hsla(240, 100, 50, 1.0), percent(75), percent(50)),
+            red.mix_keep_alpha(blue, percent(75), percent(50))
+        );
+        assert_ne!(
+            rgba(255, 0, 0, 0.0).mix(blue, percent(75)).r,
+            red.mix_keep_alpha(blue, percent(75), percent(50)).r
+        );
+    }
+
+    #[test]
+    fn can_read_perceptual_components() {
+        let tomato = rgb(255, 99, 71);
+        let oklch = OKLCH::from_rgb(tomato);
+
+        assert_eq!(tomato.oklch_lightness(), oklch.l);
+        assert_eq!(tomato.oklch_chroma(), oklch.c);
+        assert_eq!(tomato.oklch_hue(), deg_precise(oklch.h));
+
+        let transparent = rgba(255, 99, 71, 0.0);
+        assert_eq!(transparent.oklch_lightness(), tomato.oklch_lightness());
+        assert_eq!(transparent.oklch_chroma(), tomato.oklch_chroma());
+        assert_eq!(transparent.oklch_hue(), tomato.oklch_hue());
+        assert_eq!(tomato.to_hsla().oklch_hue(), tomato.oklch_hue());
+
+        assert!(rgb(0, 0, 0).oklch_lightness() < 0.001);
+        assert!((rgb(255, 255, 255).oklch_lightness() - 1.0).abs() < 0.001);
+        assert!(hsl(200, 0, 40).oklch_chroma() < 0.001);
+    }
+
+    #[test]
+    fn can_measure_luminance_over_background() {
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+        let half_red = rgba(255, 0, 0, 0.5);
+
+        let over_white = half_red.relative_luminance_over(white);
+        let over_black = half_red.relative_luminance_over(black);
+
+        assert!(over_white > over_black);
+        assert_eq!(over_white, rgb(255, 127, 127).luminance().as_f32());
+        assert_eq!(over_black, rgb(128, 0, 0).luminance().as_f32());
+
+        // Opaque colors hide the background, and the background's own alpha is ignored.
+        let red = rgb(255, 0, 0);
+        assert_eq!(red.relative_luminance_over(black), red.luminance().as_f32());
+        assert_eq!(
+            half_red.relative_luminance_over(rgba(255, 255, 255, 0.0)),
+            over_white
+        );
+        assert_eq!(
+            rgba(255, 0, 0, 0.0).relative_luminance_over(white),
+            white.luminance().as_f32()
+        );
+    }
+
+    #[test]
+    fn can_lighten_without_reaching_white() {
+        let cap = percent(96);
+
+        assert_eq!(
+            hsl(6, 93, 71).lighten_preserve_hue(percent(0), cap),
+            hsl(6, 93, 71)
+        );
+        assert_eq!(
+            hsl(6, 93, 71).lighten_preserve_hue(percent(100), cap),
+            hsl(6, 93, 96)
+        );
+        assert_eq!(
+            hsla(120, 50, 90, 0.5).lighten_preserve_hue(percent(20), cap),
+            hsla(120, 50, 96, 0.5)
+        );
+
+        // Colors beyond the cap are left alone rather than darkened.
+        assert_eq!(
+            hsl(6, 93, 98).lighten_preserve_hue(percent(10), cap),
+            hsl(6, 93, 98)
+        );
+
+        // The lightest steps of different hues stay distinct.
+        let red = rgb(255, 0, 0).lighten_preserve_hue(percent(100), cap);
+        let blue = rgb(0, 0, 255).lighten_preserve_hue(percent(100), cap);
+
+        assert_ne!(red, blue);
+        assert_ne!(red, rgb(255, 255, 255));
+        assert_eq!(red.to_hsl().l.as_percentage(), 96);
+    }
+
+    #[test]
+    fn can_compute_perceptual_sort_keys() {
+        // Reds wrap around 0°, into the same family.
+        let dark_red = hsl(355, 100, 30).perceptual_sort_key();
+        let light_red = hsl(10, 100, 70).perceptual_sort_key();
+
+        assert_eq!(dark_red.floor(), 0.0);
+        assert_eq!(light_red.floor(), 0.0);
+        assert!(dark_red < light_red);
+
+        // The hue family outweighs the lightness.
+        assert!(light_red < hsl(40, 100, 10).perceptual_sort_key());
+        assert!(hsl(340, 100, 90).perceptual_sort_key() < 12.0);
+
+        // Greys come last, from black to white.
+        let black = rgb(0, 0, 0).perceptual_sort_key();
+        let white = rgb(255, 255, 255).perceptual_sort_key();
+
+        assert!(hsl(320, 100, 95).perceptual_sort_key() < black);
+        assert_eq!(black, 12.0);
+        assert!(black < rgb(128, 130, 128).perceptual_sort_key());
+        assert!(white < 13.0);
+
+        assert_eq!(
+            rgba(0, 0, 255, 0.0).perceptual_sort_key(),
+            rgb(0, 0, 255).perceptual_sort_key()
+        );
+    }
+
+    #[test]
+    fn can_mix_opposite_alphas() {
+        let transparent_red = rgba(255, 0, 0, 0.0);
+        let opaque_blue = rgba(0, 0, 255, 1.0);
+
+        // A fully transparent color adds no color of its own, whatever its weight.
+        for &(weight, lhs_alpha, rhs_alpha) in &[(1, 252, 3), (50, 127, 128), (99, 3, 252)] {
+            assert_eq!(
+                transparent_red.mix(opaque_blue, percent(weight)),
+                rgba_u8(0, 0, 255, lhs_alpha)
+            );
+            assert_eq!(
+                opaque_blue.mix(transparent_red, percent(weight)),
+                rgba_u8(0, 0, 255, rhs_alpha)
+            );
+        }
+
+        assert_eq!(
+            transparent_red.mix(opaque_blue, percent(100)),
+            rgba(255, 0, 0, 0.0)
+        );
+        assert_eq!(
+            transparent_red.mix(opaque_blue, percent(0)),
+            rgba(0, 0, 255, 1.0)
+        );
+        assert_eq!(
+            opaque_blue.mix(transparent_red, percent(100)),
+            rgba(0, 0, 255, 1.0)
+        );
+        assert_eq!(
+            opaque_blue.mix(transparent_red, percent(0)),
+            rgba(255, 0, 0, 0.0)
+        );
+
+        // Nearly opposite weights and alphas must not blow up the rgb weight.
+        let almost_transparent = rgba_u8(255, 0, 0, 1);
+        assert_eq!(
+            almost_transparent.mix(opaque_blue, Ratio::from_u8(254)),
+            rgba_u8(85, 0, 170, 2)
+        );
+    }
+
+    #[test]
+    fn can_mix_in_each_space() {
+        let red = rgb(255, 0, 0);
+        let blue = rgba(0, 0, 255, 0.5);
+
+        for &weight in &[0, 25, 50, 100] {
+            let weight = percent(weight);
+
+            assert_eq!(
+                red.mix_in(blue, weight, MixSpace::Srgb),
+                red.mix(blue, weight)
+            );
+            assert_eq!(
+                red.mix_in(blue, weight, MixSpace::SrgbLinear),
+                red.mix_linear(blue, weight)
+            );
+            assert_eq!(
+                red.mix_in(blue, weight, MixSpace::Hsl),
+                red.to_hsla().mix_hsl(blue.to_hsla(), weight).to_rgba()
+            );
+            assert_eq!(
+                red.mix_in(blue, weight, MixSpace::Oklab),
+                red.mix_oklab(blue, weight)
+            );
+
+            // HSL colors are mixed in sRGB without a round trip through HSLA.
+            assert_eq!(
+                hsl(9, 100, 64).mix_in(blue, weight, MixSpace::Srgb),
+                hsl(9, 100, 64).to_rgba().mix(blue, weight)
+            );
+        }
+
+        // HSL takes the shorter arc from red (0°) to blue (240°), through magenta.
+        let mixed = red.mix_in(blue, percent(50), MixSpace::Hsl);
+        assert_eq!(mixed.to_hsla().h, deg(300));
+    }
+
+    #[test]
+    fn can_mix_in_oklch() {
+        let red = rgb(255, 0, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(
+            red.mix_in(blue, percent(100), MixSpace::Oklch),
+            red.to_rgba()
+        );
+        assert_eq!(
+            red.mix_in(blue, percent(0), MixSpace::Oklch),
+            blue.to_rgba()
+        );
+
+        // The hue goes the short way round, from red (29°) back to blue (264°).
+        let mixed = red.mix_in(blue, percent(50), MixSpace::Oklch);
+        let hue = mixed.oklch_hue().degrees();
+        assert!(hue > 300 && hue < 350, "{} has a hue of {}", mixed, hue);
+
+        // Unlike OKLab, OKLCH keeps the chroma up in the middle of the mix.
+        assert!(
+            mixed.oklch_chroma()
+                > red
+                    .mix_in(blue, percent(50), MixSpace::Oklab)
+                    .oklch_chroma()
+        );
+
+        // A grey has no hue, so mixing with one keeps the other color's hue.
+        let grey = rgb(128, 128, 128);
+        let mixed = red.mix_in(grey, percent(50), MixSpace::Oklch);
+        assert!((i32::from(mixed.oklch_hue().degrees()) - 29).abs() <= 1);
+
+        // The alpha channels are interpolated linearly, as in OKLab.
+        let transparent_red = rgba(255, 0, 0, 0.0);
+        assert_eq!(
+            transparent_red.mix_in(blue, percent(50), MixSpace::Oklch).a,
+            transparent_red.mix_oklab(blue, percent(50)).a
+        );
+    }
+
+    #[test]
+    fn can_mix_hsl() {
+        assert_eq!(
+            hsl(0, 100, 50).mix_hsl(hsl(120, 100, 50), percent(50)),
+            hsl(60, 100, 50)
+        );
+        assert_eq!(
+            hsl(0, 100, 50).mix_hsl(hsl(120, 100, 50), percent(100)),
+            hsl(0, 100, 50)
+        );
+        assert_eq!(
+            hsl(0, 100, 50).mix_hsl(hsl(120, 100, 50), percent(0)),
+            hsl(120, 100, 50)
+        );
+
+        // The hue takes the shorter way around the color wheel.
+        assert_eq!(
+            hsl(350, 100, 50).mix_hsl(hsl(10, 100, 50), percent(50)).h,
+            deg(0)
+        );
+        assert_eq!(
+            hsl(10, 100, 50).mix_hsl(hsl(350, 100, 50), percent(50)).h,
+            deg(0)
+        );
+
+        // A grey contributes no hue.
+        assert_eq!(
+            hsl(0, 0, 50).mix_hsl(hsl(240, 100, 50), percent(50)).h,
+            deg(240)
+        );
+
+        let mixed = hsla(120, 40, 20, 1.0).mix_hsl(hsla(120, 80, 60, 0.0), percent(50));
+        assert_eq!(mixed.s.as_percentage(), 60);
+        assert_eq!(mixed.l.as_percentage(), 40);
+        assert_eq!(mixed.a.as_percentage(), 50);
+    }
+
+    #[test]
+    fn can_mix_oklab() {
+        let red = rgb(255, 0, 0);
+        let green = rgb(0, 255, 0);
+
+        let srgb_midpoint = red.mix(green, percent(50));
+        let oklab_midpoint = red.mix_oklab(green, percent(50));
+
+        assert_eq!(srgb_midpoint, rgba(128, 127, 0, 1.0));
+        assert_eq!(oklab_midpoint, rgba(208, 168, 0, 1.0));
+        assert!(oklab_midpoint.to_hsl().l > srgb_midpoint.to_hsl().l);
+
+        assert_eq!(red.mix_oklab(green, percent(100)), red.to_rgba());
+        assert_eq!(red.mix_oklab(green, percent(0)), green.to_rgba());
+        assert_eq!(
+            hsla(0, 0, 100, 1.0)
+                .mix_oklab(rgba(0, 0, 0, 0.0), percent(50))
+                .a,
+            percent(50)
+        );
+    }
+
+    #[test]
+    fn can_tint() {
+        assert_approximately_eq!(
+            rgba(0, 0, 255, 0.5).tint(percent(50)),
+            rgba(191, 191, 255, 0.75)
+        );
+        assert_approximately_eq!(rgb(0, 0, 255).tint(percent(50)), rgb(128, 128, 255));
+        assert_approximately_eq!(hsl(6, 93, 71).tint(percent(50)), hsl(6, 92, 85));
+        assert_approximately_eq!(
+            hsla(6, 93, 71, 0.5).tint(percent(50)),
+            hsla(6, 95, 93, 0.75)
+        );
+    }
+
+    #[test]
+    fn can_shade() {
+        assert_approximately_eq!(
+            rgba(0, 0, 255, 0.5).shade(percent(50)),
+            rgba(0, 0, 64, 0.75)
+        );
+        assert_approximately_eq!(rgb(0, 0, 255).shade(percent(50)), rgb(0, 0, 128));
+        assert_approximately_eq!(hsl(6, 93, 71).shade(percent(50)), hsl(6, 38, 36));
+        assert_approximately_eq!(
+            hsla(6, 93, 71, 0.5).shade(percent(50)),
+            hsla(7, 38, 18, 0.75)
+        );
+    }
+
+    #[test]
+    fn can_greyscale() {
+        assert_approximately_eq!(rgb(128, 242, 13).greyscale(), rgb(128, 128, 128));
+        assert_approximately_eq!(
+            rgba(128, 242, 13, 1.0).greyscale(),
+            rgba(128, 128, 128, 1.0)
+        );
+        assert_approximately_eq!(hsl(90, 90, 50).greyscale(), hsl(90, 0, 50));
+        assert_approximately_eq!(hsla(90, 90, 50, 1.0).greyscale(), hsla(90, 0, 50, 1.0));
+    }
+
+    #[test]
+    fn can_measure_headroom() {
+        assert_eq!(hsl(9, 35, 50).lightness_headroom().as_percentage(), 50);
+        assert_eq!(hsl(9, 35, 50).lightness_footroom().as_percentage(), 50);
+        assert_eq!(hsl(9, 35, 50).saturation_headroom().as_percentage(), 65);
+        assert_eq!(hsl(9, 35, 50).saturation_footroom().as_percentage(), 35);
+
+        assert_eq!(
+            hsla(9, 35, 50, 0.5).saturation_headroom().as_percentage(),
+            65
+        );
+        assert_eq!(rgb(255, 255, 255).lightness_headroom(), percent(0));
+        assert_eq!(rgba(0, 0, 0, 1.0).lightness_footroom(), percent(0));
+        assert_eq!(rgb(128, 128, 128).saturation_footroom(), percent(0));
+
+        let brown = rgb(172, 96, 83);
+
+        assert_eq!(
+            brown.lighten(brown.lightness_headroom()),
+            rgb(255, 255, 255)
+        );
+        assert_eq!(brown.darken(brown.lightness_footroom()), rgb(0, 0, 0));
     }
 
     #[test]
@@ -0,0 +1,118 @@
+use super::{Color, HSL, RGB};
+use std::ops;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A table of conversions precomputed for a fixed palette, e.g. for applying the
+/// palette of an indexed-color image where the same few colors are converted
+/// over and over.
+///
+/// Every palette entry is converted once, up front, and stored, so each lookup is
+/// a single index into a `Vec`. This trades memory (one converted value per entry)
+/// and the cost of converting entries that may never be looked up for constant-time
+/// lookups afterwards. For palettes that are only converted a handful of times,
+/// converting on demand is cheaper.
+///
+/// # Example
+/// ```
+/// use css_colors::{hsl, rgb, LookupTable};
+///
+/// let palette = [rgb(255, 99, 71), rgb(100, 149, 237)];
+/// let table = LookupTable::hsl(&palette);
+///
+/// assert_eq!(table[0], hsl(9, 100, 64));
+/// assert_eq!(table.get(1).map(|color| color.h.degrees()), Some(219));
+/// assert_eq!(table.get(2), None);
+/// ```
+pub struct LookupTable<T> {
+    entries: Vec<T>,
+}
+
+impl<T> LookupTable<T> {
+    /// Builds a table by applying `convert` to every color in `palette`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, Color, LookupTable};
+    ///
+    /// let palette = [rgb(255, 99, 71), rgb(100, 149, 237)];
+    /// let table = LookupTable::new(&palette, |color| color.to_css());
+    ///
+    /// assert_eq!(table[1], "rgb(100, 149, 237)");
+    /// ```
+    pub fn new<F: Fn(RGB) -> T>(palette: &[RGB], convert: F) -> Self {
+        LookupTable {
+            entries: palette.iter().map(|&color| convert(color)).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T: Clone> LookupTable<T> {
+    /// Returns the converted color at `index`, or `None` if it falls outside of the palette.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.entries.get(index).cloned()
+    }
+}
+
+impl LookupTable<HSL> {
+    /// Builds a table of the HSL representation of every color in `palette`.
+    pub fn hsl(palette: &[RGB]) -> Self {
+        LookupTable::new(palette, Color::to_hsl)
+    }
+}
+
+impl<T> ops::Index<usize> for LookupTable<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.entries[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsl, rgb, Color, LookupTable};
+
+    #[test]
+    fn precomputes_every_entry() {
+        let palette = [rgb(0, 0, 0), rgb(255, 0, 0), rgb(255, 255, 255)];
+        let table = LookupTable::hsl(&palette);
+
+        assert_eq!(table.len(), 3);
+        assert_eq!(table[0], hsl(0, 0, 0));
+        assert_eq!(table[1], hsl(0, 100, 50));
+        assert_eq!(table[2], hsl(0, 0, 100));
+
+        for (index, color) in palette.iter().enumerate() {
+            assert_eq!(table.get(index), Some(color.to_hsl()));
+        }
+    }
+
+    #[test]
+    fn can_use_any_conversion() {
+        let table = LookupTable::new(&[rgb(255, 99, 71)], Color::to_rgba);
+
+        assert_eq!(table.get(0), Some(rgb(255, 99, 71).to_rgba()));
+    }
+
+    #[test]
+    fn handles_out_of_range_indices() {
+        let table = LookupTable::hsl(&[]);
+
+        assert!(table.is_empty());
+        assert_eq!(table.get(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_when_indexing_out_of_range() {
+        let _ = LookupTable::hsl(&[rgb(0, 0, 0)])[1];
+    }
+}
@@ -0,0 +1,101 @@
+use super::oklch::to_linear;
+use super::RGB;
+
+/// The red, green, and blue luma coefficients defined by [ITU-R BT.601](https://www.itu.int/rec/R-REC-BT.601),
+/// the standard for standard-definition television.
+pub const REC601: (f32, f32, f32) = (0.299, 0.587, 0.114);
+
+/// The red, green, and blue luma coefficients defined by [ITU-R BT.709](https://www.itu.int/rec/R-REC-BT.709),
+/// the standard for high-definition television, which sRGB shares.
+pub const REC709: (f32, f32, f32) = (0.2126, 0.7152, 0.0722);
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+/// A struct to represent the [relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance)
+/// of a color: its brightness as perceived by the eye, from `0.0` for black to `1.0` for white.
+///
+/// Computing the luminance of a color is comparatively expensive, so when the same
+/// colors are compared many times (e.g. every foreground against every background
+/// of a palette), compute each `Luminance` once and compare those instead.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, Color};
+///
+/// let foregrounds = [rgb(0, 0, 0).luminance(), rgb(255, 255, 255).luminance()];
+/// let background = rgb(255, 99, 71).luminance();
+///
+/// let best = foregrounds
+///     .iter()
+///     .map(|foreground| foreground.contrast(background))
+///     .fold(0.0, f32::max);
+///
+/// assert!((best - 7.1).abs() < 0.1);
+/// ```
+pub struct Luminance(f32);
+
+impl Luminance {
+    /// Returns the luminance as a float between `0.0` and `1.0`.
+    pub fn as_f32(self) -> f32 {
+        self.0
+    }
+
+    /// Returns the [contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio)
+    /// between two luminances, ranging from `1.0` (no contrast) to `21.0` (black on white).
+    /// The order of the two luminances does not matter.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, Color};
+    ///
+    /// let black = rgb(0, 0, 0).luminance();
+    /// let white = rgb(255, 255, 255).luminance();
+    ///
+    /// assert!((black.contrast(white) - 21.0).abs() < 0.001);
+    /// assert_eq!(black.contrast(white), white.contrast(black));
+    /// assert_eq!(white.contrast(white), 1.0);
+    /// ```
+    pub fn contrast(self, other: Luminance) -> f32 {
+        let (lighter, darker) = if self.0 > other.0 {
+            (self.0, other.0)
+        } else {
+            (other.0, self.0)
+        };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+}
+
+// Weights the channels in linear light by how bright each primary appears,
+// using the Rec. 709 coefficients.
+pub(crate) fn relative_luminance(color: RGB) -> Luminance {
+    let RGB { r, g, b } = color;
+    let (wr, wg, wb) = REC709;
+
+    Luminance(wr * to_linear(r.as_f32()) + wg * to_linear(g.as_f32()) + wb * to_linear(b.as_f32()))
+}
+
+#[cfg(test)]
+mod tests {
+    use {rgb, Color};
+
+    #[test]
+    fn can_compute_luminance() {
+        assert_eq!(rgb(0, 0, 0).luminance().as_f32(), 0.0);
+        assert!((rgb(255, 255, 255).luminance().as_f32() - 1.0).abs() < 0.0001);
+        assert!((rgb(255, 0, 0).luminance().as_f32() - 0.2126).abs() < 0.0001);
+        assert!((rgb(0, 255, 0).luminance().as_f32() - 0.7152).abs() < 0.0001);
+        assert!((rgb(0, 0, 255).luminance().as_f32() - 0.0722).abs() < 0.0001);
+        assert!(rgb(255, 255, 0).luminance() > rgb(0, 0, 255).luminance());
+    }
+
+    #[test]
+    fn can_compute_contrast() {
+        let white = rgb(255, 255, 255).luminance();
+        let grey = rgb(118, 118, 118).luminance();
+
+        // #767676 is the lightest grey that meets the WCAG AA contrast of 4.5:1 on white.
+        assert!(grey.contrast(white) >= 4.5);
+        assert!(rgb(119, 119, 119).luminance().contrast(white) < 4.5);
+        assert_eq!(grey.contrast(white), white.contrast(grey));
+    }
+}
@@ -0,0 +1,432 @@
+use super::lab::to_lab;
+use super::{named, Color, CssColor, Ratio, HSLA, RGBA};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// An enum to represent the CSS notations a color can be written in.
+pub enum ColorModel {
+    /// `rgb(r, g, b)`. Any alpha channel is dropped.
+    Rgb,
+
+    /// `rgba(r, g, b, a)`. Opaque colors are written with an alpha of `1.00`.
+    Rgba,
+
+    /// `hsl(h, s%, l%)`. Any alpha channel is dropped.
+    Hsl,
+
+    /// `hsla(h, s%, l%, a)`. Opaque colors are written with an alpha of `1.00`.
+    Hsla,
+
+    /// `#rrggbb` for opaque colors, and `#rrggbbaa` for colors with any transparency.
+    Hex,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// An enum to represent the color spaces that two colors can be mixed in, as in the
+/// `in <space>` argument of the CSS Color 5 `color-mix()` function. See `Color::mix_in`
+/// and `to_css_color_mix`.
+pub enum MixSpace {
+    /// The gamma-encoded sRGB space, which is how `Color::mix` mixes colors.
+    Srgb,
+
+    /// sRGB in linear light, which is how `Color::mix_linear` mixes colors.
+    SrgbLinear,
+
+    /// HSL, interpolating the hue along the shorter arc of the color wheel.
+    Hsl,
+
+    /// [OKLab](https://bottosson.github.io/posts/oklab/), which gives perceptually even mixes.
+    Oklab,
+
+    /// OKLCH, the cylindrical form of OKLab, interpolating the hue along the shorter arc.
+    Oklch,
+}
+
+impl MixSpace {
+    /// Returns the keyword CSS uses for `self` in `color-mix()`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::MixSpace;
+    ///
+    /// assert_eq!(MixSpace::Srgb.css_name(), "srgb");
+    /// assert_eq!(MixSpace::Oklch.css_name(), "oklch");
+    /// ```
+    pub fn css_name(self) -> &'static str {
+        match self {
+            MixSpace::Srgb => "srgb",
+            MixSpace::SrgbLinear => "srgb-linear",
+            MixSpace::Hsl => "hsl",
+            MixSpace::Oklab => "oklab",
+            MixSpace::Oklch => "oklch",
+        }
+    }
+}
+
+// Formats a color as a lowercase hex string, only including the alpha channel
+// when the color is not fully opaque.
+pub(crate) fn hex(color: RGBA) -> String {
+    let RGBA { r, g, b, a } = color;
+
+    if a.as_u8() == 255 {
+        format!("#{:02x}{:02x}{:02x}", r.as_u8(), g.as_u8(), b.as_u8())
+    } else {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            r.as_u8(),
+            g.as_u8(),
+            b.as_u8(),
+            a.as_u8()
+        )
+    }
+}
+
+// Formats a color in whichever notation gives the shortest string: 3 or 4 digit hex
+// when every channel's digits repeat, 6 or 8 digit hex, an exactly matching named
+// color, or the rgb and hsl functions. The hsl form is only a candidate when it
+// converts back to the same color. Ties go to the earliest candidate in that order.
+pub(crate) fn shortest(color: RGBA) -> String {
+    let opaque = color.a.as_u8() == 255;
+    let mut candidates = vec![short_hex(color), hex(color)];
+
+    if opaque {
+        let rgb = color.to_rgb();
+
+        candidates.extend(
+            named::ALL
+                .iter()
+                .filter(|&&(_, (r, g, b))| super::rgb(r, g, b) == rgb)
+                .map(|&(name, _)| name.to_owned()),
+        );
+        candidates.push(rgb.to_css());
+    } else {
+        candidates.push(color.to_css());
+    }
+
+    let hsla = color.to_hsla();
+
+    if hsla.to_rgba() == color {
+        candidates.push(if opaque {
+            hsla.to_hsl().to_css()
+        } else {
+            hsla.to_css()
+        });
+    }
+
+    candidates
+        .into_iter()
+        .min_by_key(String::len)
+        .unwrap_or_else(|| hex(color))
+}
+
+// Formats a color as 3 or 4 digit hex, falling back to the full form when one of
+// its channels cannot be written with a single repeated digit.
+fn short_hex(color: RGBA) -> String {
+    let RGBA { r, g, b, a } = color;
+    let channels = [r.as_u8(), g.as_u8(), b.as_u8(), a.as_u8()];
+
+    if channels.iter().any(|channel| channel % 17 != 0) {
+        return hex(color);
+    }
+
+    let digits = if a.as_u8() == 255 { 3 } else { 4 };
+
+    channels[..digits]
+        .iter()
+        .fold("#".to_owned(), |hex, channel| {
+            format!("{}{:x}", hex, channel / 17)
+        })
+}
+
+// Formats a color in the rgb, hsl and hex notations at once, for the alternate `{:#}`
+// form of `Display`. Both representations are passed in, so that neither is converted
+// back from the other. The alpha channel is only included when `alpha` is set.
+pub(crate) fn all_notations(rgba: RGBA, hsla: HSLA, alpha: bool) -> String {
+    if alpha {
+        format!("{} / {} / {}", rgba, hsla, hex(rgba))
+    } else {
+        let rgb = rgba.to_rgb();
+
+        format!("{} / {} / {}", rgb, hsla.to_hsl(), hex(rgb.to_rgba()))
+    }
+}
+
+// Formats a color using the CSS Color 4 `color()` function in the sRGB color space,
+// only including the alpha channel when the color is not fully opaque.
+pub(crate) fn color_function(color: RGBA) -> String {
+    let RGBA { r, g, b, a } = color;
+    let channels = format!(
+        "{} {} {}",
+        number(r.as_f32()),
+        number(g.as_f32()),
+        number(b.as_f32())
+    );
+
+    if a.as_u8() == 255 {
+        format!("color(srgb {})", channels)
+    } else {
+        format!("color(srgb {} / {})", channels, alpha(a))
+    }
+}
+
+// Formats a color using the CSS Color 4 `lab()` function, only including the alpha
+// channel when the color is not fully opaque.
+pub(crate) fn lab_function(color: RGBA) -> String {
+    let (l, a, b) = to_lab(color.to_rgb());
+
+    with_alpha(
+        format!("lab({}% {} {}", rounded(l, 1), rounded(a, 1), rounded(b, 1)),
+        color,
+    )
+}
+
+// Formats a color using the CSS Color 4 `lch()` function, the polar form of `lab()`,
+// only including the alpha channel when the color is not fully opaque.
+pub(crate) fn lch_function(color: RGBA) -> String {
+    let (l, a, b) = to_lab(color.to_rgb());
+    let c = (a * a + b * b).sqrt();
+
+    // Greys have no hue, but rounding errors can leave them with an arbitrary one.
+    let h = if c < 0.05 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    };
+
+    with_alpha(
+        format!("lch({}% {} {}", rounded(l, 1), rounded(c, 1), rounded(h, 1)),
+        color,
+    )
+}
+
+// Closes a space-separated CSS color function, adding the alpha channel after a
+// slash when the color is not fully opaque.
+fn with_alpha(channels: String, color: RGBA) -> String {
+    if color.a.as_u8() == 255 {
+        format!("{})", channels)
+    } else {
+        format!("{} / {})", channels, alpha(color.a))
+    }
+}
+
+// Formats an alpha channel with two decimal places, the same as the `rgba()` and `hsla()`
+// notations do, so a color's alpha reads the same in every notation.
+fn alpha(a: Ratio) -> String {
+    format!("{:.02}", a.as_f32())
+}
+
+// Formats a number with at most three decimal places, dropping any trailing zeros.
+pub(crate) fn number(value: f32) -> String {
+    rounded(value, 3)
+}
+
+// Formats a number with at most `decimals` decimal places, dropping any trailing zeros.
+fn rounded(value: f32, decimals: usize) -> String {
+    let formatted = format!("{:.*}", decimals, value);
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+
+    if formatted == "-0" {
+        "0".to_owned()
+    } else {
+        formatted.to_owned()
+    }
+}
+
+/// Converts `color` into the given color model, chosen at runtime, e.g. from a
+/// `--to hsl` command-line flag. This is the runtime counterpart to `Color::convert`.
+///
+/// `CssColor` has no hex variant, so as when parsing, the `Hex` model converts into
+/// `Rgb`, or into `Rgba` if `color` is not fully opaque. The `Rgb` and `Hsl` models
+/// drop any alpha channel.
+///
+/// # Example
+/// ```
+/// use css_colors::{convert_to, hsl, rgb, rgba, ColorModel, CssColor};
+///
+/// assert_eq!(convert_to(rgb(255, 99, 71), ColorModel::Hsl), CssColor::Hsl(hsl(9, 100, 64)));
+/// assert_eq!(convert_to(hsl(9, 100, 64), ColorModel::Hex), CssColor::Rgb(rgb(255, 99, 71)));
+/// assert_eq!(
+///     convert_to(rgba(255, 0, 0, 0.5), ColorModel::Hex),
+///     CssColor::Rgba(rgba(255, 0, 0, 0.5))
+/// );
+/// ```
+pub fn convert_to<T: Color>(color: T, model: ColorModel) -> CssColor {
+    match model {
+        ColorModel::Rgb => CssColor::Rgb(color.to_rgb()),
+        ColorModel::Rgba => CssColor::Rgba(color.to_rgba()),
+        ColorModel::Hsl => CssColor::Hsl(color.to_hsl()),
+        ColorModel::Hsla => CssColor::Hsla(color.to_hsla()),
+        ColorModel::Hex => {
+            let color = color.to_rgba();
+
+            if color.is_opaque() {
+                CssColor::Rgb(color.to_rgb())
+            } else {
+                CssColor::Rgba(color)
+            }
+        }
+    }
+}
+
+pub(crate) fn to_css_as<T: Color>(color: T, model: ColorModel) -> String {
+    match model {
+        ColorModel::Rgb => color.to_rgb().to_css(),
+        ColorModel::Rgba => color.to_rgba().to_css(),
+        ColorModel::Hsl => color.to_hsl().to_css(),
+        ColorModel::Hsla => color.to_hsla().to_css(),
+        ColorModel::Hex => hex(color.to_rgba()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {convert_to, hsl, hsla, rgb, rgba, Color, ColorModel, CssColor};
+
+    #[test]
+    fn can_convert_to_each_model() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(tomato.to_css_as(ColorModel::Rgb), "rgb(255, 99, 71)");
+        assert_eq!(
+            tomato.to_css_as(ColorModel::Rgba),
+            "rgba(255, 99, 71, 1.00)"
+        );
+        assert_eq!(tomato.to_css_as(ColorModel::Hsl), "hsl(9, 100%, 64%)");
+        assert_eq!(
+            tomato.to_css_as(ColorModel::Hsla),
+            "hsla(9, 100%, 64%, 1.00)"
+        );
+        assert_eq!(tomato.to_css_as(ColorModel::Hex), "#ff6347");
+    }
+
+    #[test]
+    fn picks_the_shortest_notation() {
+        assert_eq!(rgb(255, 255, 255).to_css_shortest(), "#fff");
+        assert_eq!(rgb(0, 0, 0).to_css_shortest(), "#000");
+        assert_eq!(rgb(255, 0, 0).to_css_shortest(), "red");
+        assert_eq!(rgb(0, 255, 255).to_css_shortest(), "#0ff");
+        assert_eq!(rgb(0, 0, 128).to_css_shortest(), "navy");
+        assert_eq!(rgb(255, 99, 71).to_css_shortest(), "tomato");
+        assert_eq!(rgb(255, 100, 71).to_css_shortest(), "#ff6447");
+        assert_eq!(rgba(255, 255, 255, 0.0).to_css_shortest(), "#fff0");
+        assert_eq!(rgba(255, 99, 71, 0.5).to_css_shortest(), "#ff634780");
+        assert_eq!(hsl(0, 0, 100).to_css_shortest(), "#fff");
+    }
+
+    #[test]
+    fn handles_alpha_per_model() {
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert_eq!(tomato.to_css_as(ColorModel::Rgb), "rgb(255, 99, 71)");
+        assert_eq!(
+            tomato.to_css_as(ColorModel::Rgba),
+            "rgba(255, 99, 71, 0.50)"
+        );
+        assert_eq!(tomato.to_css_as(ColorModel::Hsl), "hsl(9, 100%, 64%)");
+        assert_eq!(
+            tomato.to_css_as(ColorModel::Hsla),
+            "hsla(9, 100%, 64%, 0.50)"
+        );
+        assert_eq!(tomato.to_css_as(ColorModel::Hex), "#ff634780");
+    }
+
+    #[test]
+    fn can_convert_to_color_function() {
+        assert_eq!(
+            rgb(250, 99, 71).to_css_color_function(),
+            "color(srgb 0.98 0.388 0.278)"
+        );
+        assert_eq!(
+            rgb(255, 255, 255).to_css_color_function(),
+            "color(srgb 1 1 1)"
+        );
+        assert_eq!(
+            rgba(0, 0, 0, 0.5).to_css_color_function(),
+            "color(srgb 0 0 0 / 0.50)"
+        );
+        assert_eq!(
+            hsla(0, 0, 100, 0.0).to_css_color_function(),
+            "color(srgb 1 1 1 / 0.00)"
+        );
+    }
+
+    #[test]
+    fn converts_from_hsl() {
+        assert_eq!(hsl(0, 0, 100).to_css_as(ColorModel::Hex), "#ffffff");
+        assert_eq!(hsla(0, 0, 0, 0.0).to_css_as(ColorModel::Hex), "#00000000");
+        assert_eq!(
+            hsl(9, 100, 64).to_css_as(ColorModel::Hsl),
+            "hsl(9, 100%, 64%)"
+        );
+    }
+
+    #[test]
+    fn can_convert_to_lab_and_lch() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(tomato.to_css_lab(), "lab(62.2% 57.9 46.4)");
+        assert_eq!(tomato.to_css_lch(), "lch(62.2% 74.2 38.7)");
+        assert_eq!(tomato.to_hsl().to_css_lab(), tomato.to_css_lab());
+
+        assert_eq!(rgb(0, 0, 0).to_css_lab(), "lab(0% 0 0)");
+        assert_eq!(rgb(255, 255, 255).to_css_lch(), "lch(100% 0 0)");
+        assert_eq!(rgb(119, 119, 119).to_css_lch(), "lch(50% 0 0)");
+    }
+
+    #[test]
+    fn includes_alpha_in_lab_and_lch() {
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert_eq!(tomato.to_css_lab(), "lab(62.2% 57.9 46.4 / 0.50)");
+        assert_eq!(tomato.to_css_lch(), "lch(62.2% 74.2 38.7 / 0.50)");
+        assert_eq!(
+            hsla(9, 100, 64, 0.0).to_css_lab(),
+            "lab(62.2% 57.9 46.4 / 0.00)"
+        );
+    }
+
+    #[test]
+    fn can_display_all_notations() {
+        let salmon = hsla(6, 93, 71, 0.5);
+
+        assert_eq!(format!("{}", salmon), "hsla(6, 93%, 71%, 0.50)");
+        assert_eq!(
+            format!("{:#}", salmon),
+            format!("{} / {} / {}", salmon.to_rgba(), salmon, "#fa7e7080")
+        );
+        assert_eq!(
+            format!("{:#}", salmon.to_hsl()),
+            format!("{} / {} / {}", salmon.to_rgb(), salmon.to_hsl(), "#fa7e70")
+        );
+
+        let tomato = rgb(255, 99, 71);
+        assert_eq!(format!("{:#}", tomato), format!("{:#}", tomato.to_hsl()));
+        assert_eq!(
+            format!("{:#}", tomato.to_rgba()),
+            "rgba(255, 99, 71, 1.00) / hsla(9, 100%, 64%, 1.00) / #ff6347"
+        );
+    }
+
+    #[test]
+    fn can_convert_to_runtime_model() {
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert_eq!(
+            convert_to(tomato, ColorModel::Rgb),
+            CssColor::Rgb(rgb(255, 99, 71))
+        );
+        assert_eq!(convert_to(tomato, ColorModel::Rgba), CssColor::Rgba(tomato));
+        assert_eq!(
+            convert_to(tomato, ColorModel::Hsl),
+            CssColor::Hsl(tomato.to_hsl())
+        );
+        assert_eq!(
+            convert_to(tomato, ColorModel::Hsla),
+            CssColor::Hsla(tomato.to_hsla())
+        );
+        assert_eq!(convert_to(tomato, ColorModel::Hex), CssColor::Rgba(tomato));
+        assert_eq!(
+            convert_to(hsla(9, 100, 64, 1.0), ColorModel::Hex),
+            CssColor::Rgb(rgb(255, 99, 71))
+        );
+    }
+}
@@ -0,0 +1,448 @@
+//! The [named colors](https://www.w3.org/TR/css-color-4/#named-colors) defined by CSS.
+
+use super::{rgb, RGB};
+
+/// Returns the CSS named colors grouped into rough hue families, in an order
+/// suitable for presenting them in a color picker.
+///
+/// The groups are `reds`, `pinks`, `oranges`, `yellows`, `purples`, `greens`,
+/// `blues`, `browns`, `whites` and `grays`. Every named color appears in
+/// exactly one group, except for the British `grey` spellings (e.g. `darkgrey`),
+/// which are left out in favor of their `gray` equivalents.
+///
+/// # Example
+/// ```
+/// use css_colors::{named, rgb};
+///
+/// let (name, reds) = named::groups()[0];
+///
+/// assert_eq!(name, "reds");
+/// assert!(reds.contains(&("crimson", rgb(220, 20, 60))));
+/// ```
+pub fn groups() -> &'static [(&'static str, &'static [(&'static str, RGB)])] {
+    GROUPS
+}
+
+/// Returns the canonical, lowercase name of a CSS named color, ignoring case.
+///
+/// The British `grey` spellings are normalized to their `gray` equivalents, so
+/// that both spellings of the same color share a single canonical name.
+///
+/// # Example
+/// ```
+/// use css_colors::named;
+///
+/// assert_eq!(named::canonical("CornFlowerBlue"), Some("cornflowerblue"));
+/// assert_eq!(named::canonical("DarkGrey"), Some("darkgray"));
+/// assert_eq!(named::canonical("blurple"), None);
+/// ```
+pub fn canonical(name: &str) -> Option<&'static str> {
+    let name = name.to_ascii_lowercase();
+    let name = ALIASES
+        .iter()
+        .find(|&&(alias, _)| alias == name)
+        .map_or(name.as_str(), |&(_, canonical)| canonical);
+
+    GROUPS
+        .iter()
+        .flat_map(|&(_, colors)| colors.iter())
+        .find(|&&(candidate, _)| candidate == name)
+        .map(|&(candidate, _)| candidate)
+}
+
+/// Returns whether `name` is a CSS named color, ignoring case.
+///
+/// # Example
+/// ```
+/// use css_colors::named;
+///
+/// assert!(named::is_valid_css_name("RebeccaPurple"));
+/// assert!(named::is_valid_css_name("lightgrey"));
+/// assert!(!named::is_valid_css_name("blurple"));
+/// ```
+pub fn is_valid_css_name(name: &str) -> bool {
+    canonical(name).is_some()
+}
+
+/// Looks up a CSS named color, ignoring case.
+/// Accepts both the `gray` and `grey` spellings of the grey colors.
+pub(crate) fn lookup(name: &str) -> Option<RGB> {
+    let name = canonical(name)?;
+
+    GROUPS
+        .iter()
+        .flat_map(|&(_, colors)| colors.iter())
+        .find(|&&(candidate, _)| candidate == name)
+        .map(|&(_, color)| color)
+}
+
+/// Finds the named color closest to `color`, as long as none of its channels
+/// differ by more than `tolerance`. Colors with two names (e.g. `aqua` and `cyan`)
+/// resolve to the first one in `groups()`.
+pub(crate) fn nearest(color: RGB, tolerance: u8) -> Option<&'static str> {
+    let distance = |candidate: RGB| {
+        [
+            (color.r, candidate.r),
+            (color.g, candidate.g),
+            (color.b, candidate.b),
+        ]
+        .iter()
+        .map(|&(lhs, rhs)| (i16::from(lhs.as_u8()) - i16::from(rhs.as_u8())).abs())
+        .max()
+        .unwrap_or(0)
+    };
+
+    GROUPS
+        .iter()
+        .flat_map(|&(_, colors)| colors.iter())
+        .map(|&(name, candidate)| (distance(candidate), name))
+        .filter(|&(distance, _)| distance <= i16::from(tolerance))
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, name)| name)
+}
+
+/// Suggests the named color closest to a lowercase `name` that is not itself a
+/// named color, e.g. to correct a typo. Names that need more edits than a third
+/// of their length (but at least one) are considered too different to suggest.
+pub(crate) fn suggest(name: &str) -> Option<&'static str> {
+    let limit = (name.chars().count() / 3).max(1);
+
+    GROUPS
+        .iter()
+        .flat_map(|&(_, colors)| colors.iter().map(|&(candidate, _)| candidate))
+        .chain(ALIASES.iter().map(|&(alias, _)| alias))
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= limit)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+// The Levenshtein distance between two strings: the number of single character
+// insertions, deletions, or substitutions needed to turn one into the other.
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+    let mut previous: Vec<usize> = (0..=rhs.len()).collect();
+
+    for (i, lhs) in lhs.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, &rhs) in rhs.iter().enumerate() {
+            let substitution = previous[j] + if lhs == rhs { 0 } else { 1 };
+            let insertion = current[j] + 1;
+            let deletion = previous[j + 1] + 1;
+
+            current.push(substitution.min(insertion).min(deletion));
+        }
+
+        previous = current;
+    }
+
+    previous[rhs.len()]
+}
+
+// The British spellings of the grey colors, which CSS accepts as aliases.
+static ALIASES: &[(&str, &str)] = &[
+    ("darkgrey", "darkgray"),
+    ("darkslategrey", "darkslategray"),
+    ("dimgrey", "dimgray"),
+    ("grey", "gray"),
+    ("lightgrey", "lightgray"),
+    ("lightslategrey", "lightslategray"),
+    ("slategrey", "slategray"),
+];
+
+// Defines both `GROUPS` and `ALL` from a single listing of the named colors, so the
+// two tables can never disagree.
+macro_rules! named_colors {
+    ($($group:expr => [$(($name:expr, $r:expr, $g:expr, $b:expr),)*],)*) => {
+        static GROUPS: &[(&str, &[(&str, RGB)])] = &[$(($group, &[$(($name, rgb($r, $g, $b)),)*]),)*];
+
+        /// Every CSS named color as a name and `(red, green, blue)` tuple, in the order of
+        /// `groups()`. As with `groups()`, the British `grey` spellings are left out in favor
+        /// of their `gray` equivalents; use `canonical` to normalize a name before matching.
+        ///
+        /// Unlike `groups()`, this is a `const` of plain tuples, so it can be used in
+        /// `const` contexts and for code generation.
+        ///
+        /// # Example
+        /// ```
+        /// use css_colors::named;
+        ///
+        /// const TOMATO: (u8, u8, u8) = named::ALL[16].1;
+        ///
+        /// assert_eq!(named::ALL[16], ("tomato", TOMATO));
+        /// assert_eq!(named::ALL.len(), 141);
+        /// ```
+        pub const ALL: &[(&str, (u8, u8, u8))] = &[$($(($name, ($r, $g, $b)),)*)*];
+    };
+}
+
+named_colors! {
+    "reds" => [
+        ("indianred", 205, 92, 92),
+        ("lightcoral", 240, 128, 128),
+        ("salmon", 250, 128, 114),
+        ("darksalmon", 233, 150, 122),
+        ("lightsalmon", 255, 160, 122),
+        ("crimson", 220, 20, 60),
+        ("red", 255, 0, 0),
+        ("firebrick", 178, 34, 34),
+        ("darkred", 139, 0, 0),
+    ],
+    "pinks" => [
+        ("pink", 255, 192, 203),
+        ("lightpink", 255, 182, 193),
+        ("hotpink", 255, 105, 180),
+        ("deeppink", 255, 20, 147),
+        ("mediumvioletred", 199, 21, 133),
+        ("palevioletred", 219, 112, 147),
+    ],
+    "oranges" => [
+        ("coral", 255, 127, 80),
+        ("tomato", 255, 99, 71),
+        ("orangered", 255, 69, 0),
+        ("darkorange", 255, 140, 0),
+        ("orange", 255, 165, 0),
+    ],
+    "yellows" => [
+        ("gold", 255, 215, 0),
+        ("yellow", 255, 255, 0),
+        ("lightyellow", 255, 255, 224),
+        ("lemonchiffon", 255, 250, 205),
+        ("lightgoldenrodyellow", 250, 250, 210),
+        ("papayawhip", 255, 239, 213),
+        ("moccasin", 255, 228, 181),
+        ("peachpuff", 255, 218, 185),
+        ("palegoldenrod", 238, 232, 170),
+        ("khaki", 240, 230, 140),
+        ("darkkhaki", 189, 183, 107),
+    ],
+    "purples" => [
+        ("lavender", 230, 230, 250),
+        ("thistle", 216, 191, 216),
+        ("plum", 221, 160, 221),
+        ("violet", 238, 130, 238),
+        ("orchid", 218, 112, 214),
+        ("fuchsia", 255, 0, 255),
+        ("magenta", 255, 0, 255),
+        ("mediumorchid", 186, 85, 211),
+        ("mediumpurple", 147, 112, 219),
+        ("rebeccapurple", 102, 51, 153),
+        ("blueviolet", 138, 43, 226),
+        ("darkviolet", 148, 0, 211),
+        ("darkorchid", 153, 50, 204),
+        ("darkmagenta", 139, 0, 139),
+        ("purple", 128, 0, 128),
+        ("indigo", 75, 0, 130),
+        ("slateblue", 106, 90, 205),
+        ("darkslateblue", 72, 61, 139),
+        ("mediumslateblue", 123, 104, 238),
+    ],
+    "greens" => [
+        ("greenyellow", 173, 255, 47),
+        ("chartreuse", 127, 255, 0),
+        ("lawngreen", 124, 252, 0),
+        ("lime", 0, 255, 0),
+        ("limegreen", 50, 205, 50),
+        ("palegreen", 152, 251, 152),
+        ("lightgreen", 144, 238, 144),
+        ("mediumspringgreen", 0, 250, 154),
+        ("springgreen", 0, 255, 127),
+        ("mediumseagreen", 60, 179, 113),
+        ("seagreen", 46, 139, 87),
+        ("forestgreen", 34, 139, 34),
+        ("green", 0, 128, 0),
+        ("darkgreen", 0, 100, 0),
+        ("yellowgreen", 154, 205, 50),
+        ("olivedrab", 107, 142, 35),
+        ("olive", 128, 128, 0),
+        ("darkolivegreen", 85, 107, 47),
+        ("mediumaquamarine", 102, 205, 170),
+        ("darkseagreen", 143, 188, 143),
+        ("lightseagreen", 32, 178, 170),
+        ("darkcyan", 0, 139, 139),
+        ("teal", 0, 128, 128),
+    ],
+    "blues" => [
+        ("aqua", 0, 255, 255),
+        ("cyan", 0, 255, 255),
+        ("lightcyan", 224, 255, 255),
+        ("paleturquoise", 175, 238, 238),
+        ("aquamarine", 127, 255, 212),
+        ("turquoise", 64, 224, 208),
+        ("mediumturquoise", 72, 209, 204),
+        ("darkturquoise", 0, 206, 209),
+        ("cadetblue", 95, 158, 160),
+        ("steelblue", 70, 130, 180),
+        ("lightsteelblue", 176, 196, 222),
+        ("powderblue", 176, 224, 230),
+        ("lightblue", 173, 216, 230),
+        ("skyblue", 135, 206, 235),
+        ("lightskyblue", 135, 206, 250),
+        ("deepskyblue", 0, 191, 255),
+        ("dodgerblue", 30, 144, 255),
+        ("cornflowerblue", 100, 149, 237),
+        ("royalblue", 65, 105, 225),
+        ("blue", 0, 0, 255),
+        ("mediumblue", 0, 0, 205),
+        ("darkblue", 0, 0, 139),
+        ("navy", 0, 0, 128),
+        ("midnightblue", 25, 25, 112),
+    ],
+    "browns" => [
+        ("cornsilk", 255, 248, 220),
+        ("blanchedalmond", 255, 235, 205),
+        ("bisque", 255, 228, 196),
+        ("navajowhite", 255, 222, 173),
+        ("wheat", 245, 222, 179),
+        ("burlywood", 222, 184, 135),
+        ("tan", 210, 180, 140),
+        ("rosybrown", 188, 143, 143),
+        ("sandybrown", 244, 164, 96),
+        ("goldenrod", 218, 165, 32),
+        ("darkgoldenrod", 184, 134, 11),
+        ("peru", 205, 133, 63),
+        ("chocolate", 210, 105, 30),
+        ("saddlebrown", 139, 69, 19),
+        ("sienna", 160, 82, 45),
+        ("brown", 165, 42, 42),
+        ("maroon", 128, 0, 0),
+    ],
+    "whites" => [
+        ("white", 255, 255, 255),
+        ("snow", 255, 250, 250),
+        ("honeydew", 240, 255, 240),
+        ("mintcream", 245, 255, 250),
+        ("azure", 240, 255, 255),
+        ("aliceblue", 240, 248, 255),
+        ("ghostwhite", 248, 248, 255),
+        ("whitesmoke", 245, 245, 245),
+        ("seashell", 255, 245, 238),
+        ("beige", 245, 245, 220),
+        ("oldlace", 253, 245, 230),
+        ("floralwhite", 255, 250, 240),
+        ("ivory", 255, 255, 240),
+        ("antiquewhite", 250, 235, 215),
+        ("linen", 250, 240, 230),
+        ("lavenderblush", 255, 240, 245),
+        ("mistyrose", 255, 228, 225),
+    ],
+    "grays" => [
+        ("gainsboro", 220, 220, 220),
+        ("lightgray", 211, 211, 211),
+        ("silver", 192, 192, 192),
+        ("darkgray", 169, 169, 169),
+        ("gray", 128, 128, 128),
+        ("dimgray", 105, 105, 105),
+        ("lightslategray", 119, 136, 153),
+        ("slategray", 112, 128, 144),
+        ("darkslategray", 47, 79, 79),
+        ("black", 0, 0, 0),
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use named::{
+        canonical, edit_distance, groups, is_valid_css_name, lookup, nearest, suggest, ALL,
+    };
+    use {rgb, RGB};
+
+    #[test]
+    fn groups_every_named_color_once() {
+        let mut names: Vec<&str> = groups()
+            .iter()
+            .flat_map(|&(_, colors)| colors.iter().map(|&(name, _)| name))
+            .collect();
+        let count = names.len();
+
+        names.sort();
+        names.dedup();
+
+        assert_eq!(names.len(), count);
+        assert_eq!(count, 141);
+    }
+
+    #[test]
+    fn can_lookup_names() {
+        assert_eq!(lookup("tomato"), Some(rgb(255, 99, 71)));
+        assert_eq!(lookup("CornflowerBlue"), Some(rgb(100, 149, 237)));
+        assert_eq!(lookup("darkgrey"), lookup("darkgray"));
+        assert_eq!(lookup("grey"), Some(rgb(128, 128, 128)));
+        assert_eq!(lookup("tomatoes"), None);
+    }
+
+    #[test]
+    fn can_canonicalize_names() {
+        assert_eq!(canonical("tomato"), Some("tomato"));
+        assert_eq!(canonical("CornFlowerBlue"), Some("cornflowerblue"));
+        assert_eq!(canonical("grey"), Some("gray"));
+        assert_eq!(canonical("LightSlateGrey"), Some("lightslategray"));
+        assert_eq!(canonical("tomatoes"), None);
+        assert_eq!(canonical(""), None);
+
+        assert!(is_valid_css_name("DARKGREY"));
+        assert!(!is_valid_css_name("transparent"));
+    }
+
+    #[test]
+    fn can_find_nearest_names() {
+        assert_eq!(nearest(rgb(255, 99, 71), 0), Some("tomato"));
+        assert_eq!(nearest(rgb(254, 100, 71), 0), None);
+        assert_eq!(nearest(rgb(254, 100, 71), 1), Some("tomato"));
+        assert_eq!(nearest(rgb(0, 255, 255), 0), Some("aqua"));
+        assert_eq!(nearest(rgb(128, 128, 129), 2), Some("gray"));
+        assert_eq!(nearest(rgb(17, 34, 51), 5), None);
+    }
+
+    #[test]
+    fn can_measure_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("tomato", "tomato"), 0);
+        assert_eq!(edit_distance("tomatoo", "tomato"), 1);
+        assert_eq!(edit_distance("tomat", "tomato"), 1);
+        assert_eq!(edit_distance("tomoto", "tomato"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "red"), 3);
+    }
+
+    #[test]
+    fn can_suggest_names() {
+        assert_eq!(suggest("tomatoo"), Some("tomato"));
+        assert_eq!(suggest("cornflowerbleu"), Some("cornflowerblue"));
+        assert_eq!(suggest("lightgreyy"), Some("lightgrey"));
+        assert_eq!(suggest("rde"), None);
+        assert_eq!(suggest("xyzzy"), None);
+    }
+
+    #[test]
+    fn has_no_empty_groups() {
+        assert_eq!(groups().len(), 10);
+
+        for &(name, colors) in groups() {
+            assert!(!colors.is_empty(), "{} is empty", name);
+        }
+    }
+
+    #[test]
+    fn all_matches_groups() {
+        let grouped: Vec<(&str, (u8, u8, u8))> = groups()
+            .iter()
+            .flat_map(|&(_, colors)| colors.iter())
+            .map(|&(name, color)| {
+                let RGB { r, g, b } = color;
+                (name, (r.as_u8(), g.as_u8(), b.as_u8()))
+            })
+            .collect();
+
+        assert_eq!(ALL, &grouped[..]);
+        assert!(ALL.iter().all(|&(name, _)| canonical(name) == Some(name)));
+    }
+
+    #[test]
+    fn all_is_usable_in_const_contexts() {
+        const FIRST: (&str, (u8, u8, u8)) = ALL[0];
+
+        assert_eq!(FIRST, ("indianred", (205, 92, 92)));
+    }
+}
@@ -0,0 +1,528 @@
+use super::{Color, Ratio, RGB, RGBA};
+
+/// Constructs an OKLCH color from numerical values, similar to the
+/// [`oklch` function](https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch) in CSS.
+///
+/// The lightness is clamped to the `0.0-1.0` range, and a negative chroma is clamped
+/// to `0.0`. The hue is expressed in degrees, and values outside of the 0-360° range
+/// will be normalized accordingly. Non-finite values will cause a panic.
+///
+/// The resulting color may fall outside of sRGB; `OKLCH::to_rgb` maps it back into gamut.
+///
+/// # Example
+/// ```
+/// use css_colors::{oklch, rgb};
+///
+/// assert_eq!(oklch(1.0, 0.0, 0.0).to_rgb(), rgb(255, 255, 255));
+/// assert_eq!(oklch(1.5, -0.1, 400.0), oklch(1.0, 0.0, 40.0));
+/// ```
+pub fn oklch(l: f32, c: f32, h: f32) -> OKLCH {
+    assert!(
+        l.is_finite() && c.is_finite() && h.is_finite(),
+        "Invalid value for OKLCH component"
+    );
+
+    OKLCH {
+        l: l.clamp(0.0, 1.0),
+        c: c.max(0.0),
+        h: h.rem_euclid(360.0),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent a color in the OKLCH color space, a cylindrical form of
+/// [OKLab](https://bottosson.github.io/posts/oklab/).
+///
+/// Unlike HSL, equal steps in OKLCH lightness and chroma look like equal steps to
+/// the eye, which makes it a good space for generating ramps and adjusting colors.
+/// The lightness ranges between `0.0-1.0`, where `0.0` is black and `1.0` is white.
+/// The chroma starts at `0.0` (grey) and, for colors inside sRGB, stays below `0.37`.
+/// The hue is expressed in degrees on the color wheel.
+///
+/// Many OKLCH values have no sRGB equivalent. Use `clamp_to_srgb` to bring them
+/// back into gamut.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#ok-lab).
+pub struct OKLCH {
+    // lightness
+    pub l: f32,
+
+    // chroma
+    pub c: f32,
+
+    // hue
+    pub h: f32,
+}
+
+// How far outside of [0.0, 1.0] a linear channel may fall and still be considered in gamut.
+const GAMUT_EPSILON: f32 = 0.0001;
+
+// The chroma below which a color is treated as a grey, without a meaningful hue.
+const ACHROMATIC_CHROMA: f32 = 0.001;
+
+// The chroma precision at which the gamut-mapping search stops.
+const CHROMA_EPSILON: f32 = 0.0001;
+
+impl OKLCH {
+    /// Converts an RGB color into its OKLCH representation.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, OKLCH};
+    ///
+    /// let white = OKLCH::from_rgb(rgb(255, 255, 255));
+    ///
+    /// assert!((white.l - 1.0).abs() < 0.001);
+    /// assert!(white.c < 0.001);
+    /// ```
+    pub fn from_rgb(color: RGB) -> Self {
+        let (l, a, b) = linear_srgb_to_oklab(
+            to_linear(color.r.as_f32()),
+            to_linear(color.g.as_f32()),
+            to_linear(color.b.as_f32()),
+        );
+
+        let c = (a * a + b * b).sqrt();
+        let h = b.atan2(a).to_degrees();
+
+        OKLCH {
+            l,
+            c,
+            h: if h < 0.0 { h + 360.0 } else { h },
+        }
+    }
+
+    /// Converts `self` into its RGB representation. Colors outside of the sRGB gamut
+    /// are gamut-mapped by reducing their chroma, as with `clamp_to_srgb`, so the
+    /// lightness and hue of the requested color are kept as closely as possible.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{oklch, rgb, OKLCH};
+    ///
+    /// let tomato = OKLCH::from_rgb(rgb(255, 99, 71));
+    ///
+    /// assert_eq!(tomato.to_rgb(), rgb(255, 99, 71));
+    /// assert_eq!(oklch(0.7, 0.4, 30.0).to_rgb(), oklch(0.7, 0.4, 30.0).clamp_to_srgb());
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        self.clamp_to_srgb()
+    }
+
+    /// Returns whether `self` can be represented in sRGB without any clipping.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::OKLCH;
+    ///
+    /// assert!(OKLCH { l: 0.7, c: 0.1, h: 30.0 }.is_in_srgb_gamut());
+    /// assert!(!OKLCH { l: 0.7, c: 0.4, h: 30.0 }.is_in_srgb_gamut());
+    /// ```
+    pub fn is_in_srgb_gamut(self) -> bool {
+        let (r, g, b) = self.to_linear_srgb();
+
+        in_gamut(r) && in_gamut(g) && in_gamut(b)
+    }
+
+    /// Maps `self` into the sRGB gamut, returning the equivalent RGB color.
+    ///
+    /// Colors that are already in gamut are converted directly. Otherwise, the
+    /// lightness and hue are kept fixed while the chroma is reduced until the color
+    /// fits, using a binary search between no chroma (always in gamut) and the
+    /// requested chroma. Lightness values at or beyond the ends of the range map to
+    /// black and white. This avoids the hue shifts and flat, posterized results of
+    /// clamping each RGB channel independently.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::OKLCH;
+    ///
+    /// let vivid_red = OKLCH { l: 0.6, c: 0.4, h: 25.0 };
+    /// let mapped = vivid_red.clamp_to_srgb();
+    ///
+    /// assert!(OKLCH::from_rgb(mapped).c < vivid_red.c);
+    /// assert!((OKLCH::from_rgb(mapped).l - vivid_red.l).abs() < 0.01);
+    /// ```
+    pub fn clamp_to_srgb(self) -> RGB {
+        if self.l >= 1.0 {
+            return OKLCH {
+                l: 1.0,
+                c: 0.0,
+                ..self
+            }
+            .to_rgb_clipped();
+        }
+
+        if self.l <= 0.0 {
+            return OKLCH {
+                l: 0.0,
+                c: 0.0,
+                ..self
+            }
+            .to_rgb_clipped();
+        }
+
+        if self.is_in_srgb_gamut() {
+            return self.to_rgb_clipped();
+        }
+
+        let mut min = 0.0;
+        let mut max = self.c;
+
+        while max - min > CHROMA_EPSILON {
+            let chroma = (min + max) / 2.0;
+
+            if (OKLCH { c: chroma, ..self }).is_in_srgb_gamut() {
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+
+        OKLCH { c: min, ..self }.to_rgb_clipped()
+    }
+
+    fn to_linear_srgb(self) -> (f32, f32, f32) {
+        let h = self.h.to_radians();
+
+        oklab_to_linear_srgb(self.l, self.c * h.cos(), self.c * h.sin())
+    }
+
+    // Converts to RGB, clamping any channel that still falls slightly outside of the gamut.
+    fn to_rgb_clipped(self) -> RGB {
+        let (r, g, b) = self.to_linear_srgb();
+
+        RGB {
+            r: Ratio::clamp(from_linear(r)),
+            g: Ratio::clamp(from_linear(g)),
+            b: Ratio::clamp(from_linear(b)),
+        }
+    }
+}
+
+fn in_gamut(value: f32) -> bool {
+    (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(&value)
+}
+
+// Interpolates between two colors in OKLab, where `weight` is the proportion of `lhs`.
+pub(crate) fn mix_oklab(lhs: RGBA, rhs: RGBA, weight: f32) -> RGBA {
+    let to_oklab = |color: RGBA| {
+        linear_srgb_to_oklab(
+            to_linear(color.r.as_f32()),
+            to_linear(color.g.as_f32()),
+            to_linear(color.b.as_f32()),
+        )
+    };
+    let mix = |lhs: f32, rhs: f32| lhs * weight + rhs * (1.0 - weight);
+
+    let (l_lhs, a_lhs, b_lhs) = to_oklab(lhs);
+    let (l_rhs, a_rhs, b_rhs) = to_oklab(rhs);
+    let (r, g, b) = oklab_to_linear_srgb(mix(l_lhs, l_rhs), mix(a_lhs, a_rhs), mix(b_lhs, b_rhs));
+
+    RGBA {
+        r: Ratio::clamp(from_linear(r)),
+        g: Ratio::clamp(from_linear(g)),
+        b: Ratio::clamp(from_linear(b)),
+        a: Ratio::clamp(mix(lhs.a.as_f32(), rhs.a.as_f32())),
+    }
+}
+
+// Interpolates between two colors in OKLCH, taking the shorter arc between the hues.
+// A grey has no meaningful hue, so when one side is achromatic the other side's hue is kept.
+pub(crate) fn mix_oklch(lhs: RGBA, rhs: RGBA, weight: f32) -> RGBA {
+    let mix = |lhs: f32, rhs: f32| lhs * weight + rhs * (1.0 - weight);
+
+    let lch_lhs = OKLCH::from_rgb(lhs.to_rgb());
+    let lch_rhs = OKLCH::from_rgb(rhs.to_rgb());
+
+    let h = if lch_lhs.c < ACHROMATIC_CHROMA {
+        lch_rhs.h
+    } else if lch_rhs.c < ACHROMATIC_CHROMA {
+        lch_lhs.h
+    } else {
+        // Take the signed difference along the shorter arc, in the range [-180, 180).
+        let difference = (lch_rhs.h - lch_lhs.h + 540.0) % 360.0 - 180.0;
+        lch_lhs.h + difference * (1.0 - weight)
+    };
+
+    let RGB { r, g, b } = oklch(mix(lch_lhs.l, lch_rhs.l), mix(lch_lhs.c, lch_rhs.c), h).to_rgb();
+
+    RGBA {
+        r,
+        g,
+        b,
+        a: Ratio::clamp(mix(lhs.a.as_f32(), rhs.a.as_f32())),
+    }
+}
+
+// Adds `amount` to the OKLab lightness of `color`, keeping its chroma and hue where the
+// sRGB gamut allows and leaving its alpha channel untouched.
+pub(crate) fn lighten_oklab(color: RGBA, amount: f32) -> RGBA {
+    let lch = OKLCH::from_rgb(color.to_rgb());
+    let RGB { r, g, b } = OKLCH {
+        l: (lch.l + amount).clamp(0.0, 1.0),
+        ..lch
+    }
+    .to_rgb();
+
+    RGBA {
+        r,
+        g,
+        b,
+        a: color.a,
+    }
+}
+
+// Adds `amount` to the chroma of `color`, which stops at zero, keeping its lightness
+// and hue. Greys have no hue to add chroma along, so they are returned unchanged.
+pub(crate) fn adjust_chroma(color: RGB, amount: f32) -> RGB {
+    let lch = OKLCH::from_rgb(color);
+
+    if lch.c < ACHROMATIC_CHROMA {
+        return color;
+    }
+
+    OKLCH {
+        c: (lch.c + amount).max(0.0),
+        ..lch
+    }
+    .to_rgb()
+}
+
+pub(crate) fn mix_linear(lhs: RGBA, rhs: RGBA, weight: f32) -> RGBA {
+    let mix = |lhs: Ratio, rhs: Ratio| {
+        let linear = to_linear(lhs.as_f32()) * weight + to_linear(rhs.as_f32()) * (1.0 - weight);
+        Ratio::clamp(from_linear(linear))
+    };
+
+    RGBA {
+        r: mix(lhs.r, rhs.r),
+        g: mix(lhs.g, rhs.g),
+        b: mix(lhs.b, rhs.b),
+        a: Ratio::clamp(lhs.a.as_f32() * weight + rhs.a.as_f32() * (1.0 - weight)),
+    }
+}
+
+// Converts a gamma-encoded sRGB channel into linear light.
+pub(crate) fn to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Converts a linear light channel back into its gamma-encoded sRGB value.
+pub(crate) fn from_linear(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// The matrices below are taken from Björn Ottosson's reference implementation
+// (https://bottosson.github.io/posts/oklab/#converting-from-linear-srgb-to-oklab).
+pub(crate) fn linear_srgb_to_oklab(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+    let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+    let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+    let l = l.cbrt();
+    let m = m.cbrt();
+    let s = s.cbrt();
+
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+pub(crate) fn oklab_to_linear_srgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    (
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsl, oklch, percent, rgb, rgba, Color, OKLCH};
+
+    #[test]
+    fn lightens_every_hue_by_the_same_perceived_amount() {
+        let colors = [
+            hsl(0, 100, 40),
+            hsl(60, 100, 40),
+            hsl(120, 100, 40),
+            hsl(240, 100, 40),
+        ];
+        let spread = |deltas: &[f32]| {
+            let max = deltas.iter().cloned().fold(f32::MIN, f32::max);
+            let min = deltas.iter().cloned().fold(f32::MAX, f32::min);
+            max - min
+        };
+
+        let hsl_deltas: Vec<f32> = colors
+            .iter()
+            .map(|&color| color.lighten(percent(20)).oklch_lightness() - color.oklch_lightness())
+            .collect();
+        let oklab_deltas: Vec<f32> = colors
+            .iter()
+            .map(|&color| color.lighten_oklab(0.1).oklch_lightness() - color.oklch_lightness())
+            .collect();
+
+        // HSL lightening changes the perceived lightness of each hue by a different amount.
+        assert!(spread(&hsl_deltas) > 0.03);
+
+        for &delta in &oklab_deltas {
+            assert!((delta - 0.1).abs() < 0.01);
+        }
+        assert!(spread(&oklab_deltas) < 0.01);
+    }
+
+    #[test]
+    fn adjusts_chroma() {
+        // The same boost raises the chroma of a blue and a yellow by the same amount.
+        for &color in &[rgb(60, 70, 140), rgb(200, 190, 90)] {
+            let boosted = color.boost_chroma(0.03);
+            assert!((boosted.oklch_chroma() - color.oklch_chroma() - 0.03).abs() < 0.005);
+            assert!((boosted.oklch_lightness() - color.oklch_lightness()).abs() < 0.005);
+
+            let reduced = color.reduce_chroma(0.03);
+            assert!((color.oklch_chroma() - reduced.oklch_chroma() - 0.03).abs() < 0.005);
+        }
+
+        // The chroma stops at zero, keeping the lightness.
+        let tomato = rgb(255, 99, 71);
+        let grey = tomato.reduce_chroma(1.0);
+        assert!(grey.oklch_chroma() < 0.005);
+        assert!((grey.oklch_lightness() - tomato.oklch_lightness()).abs() < 0.005);
+
+        // Greys are left alone.
+        assert_eq!(rgb(128, 128, 128).boost_chroma(0.1), rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn clamps_boosted_chroma_to_the_gamut() {
+        let color = rgb(200, 60, 50);
+        let boosted = color.boost_chroma(1.0);
+
+        assert!(boosted.oklch_chroma() > color.oklch_chroma());
+        assert!((boosted.oklch_lightness() - color.oklch_lightness()).abs() < 0.01);
+        assert!(boosted.oklch_hue().distance(color.oklch_hue()).degrees() <= 1);
+
+        // Colors already at the edge of the gamut have no room to grow.
+        assert!(rgb(255, 0, 0).boost_chroma(0.1).delta_e(rgb(255, 0, 0)) < 1.0);
+    }
+
+    #[test]
+    fn lightens_and_darkens_in_oklab() {
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert_eq!(tomato.lighten_oklab(0.0), tomato);
+        assert_eq!(tomato.lighten_oklab(1.0), rgba(255, 255, 255, 0.5));
+        assert_eq!(tomato.darken_oklab(1.0), rgba(0, 0, 0, 0.5));
+        assert_eq!(tomato.darken_oklab(0.1), tomato.lighten_oklab(-0.1));
+    }
+
+    #[test]
+    fn can_convert_from_rgb() {
+        let red = OKLCH::from_rgb(rgb(255, 0, 0));
+
+        assert!((red.l - 0.628).abs() < 0.001);
+        assert!((red.c - 0.258).abs() < 0.001);
+        assert!((red.h - 29.23).abs() < 0.1);
+    }
+
+    #[test]
+    fn round_trips_in_gamut_colors() {
+        for color in &[
+            rgb(255, 99, 71),
+            rgb(100, 149, 237),
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+        ] {
+            assert_eq!(OKLCH::from_rgb(*color).clamp_to_srgb(), *color);
+        }
+    }
+
+    #[test]
+    fn clamps_by_reducing_chroma() {
+        let out_of_gamut = OKLCH {
+            l: 0.7,
+            c: 0.35,
+            h: 150.0,
+        };
+        let clamped = OKLCH::from_rgb(out_of_gamut.clamp_to_srgb());
+
+        assert!(!out_of_gamut.is_in_srgb_gamut());
+        assert!(clamped.c < out_of_gamut.c);
+        assert!((clamped.l - out_of_gamut.l).abs() < 0.01);
+        assert!((clamped.h - out_of_gamut.h).abs() < 2.0);
+    }
+
+    #[test]
+    fn clamps_lightness_extremes() {
+        let too_light = OKLCH {
+            l: 1.2,
+            c: 0.2,
+            h: 90.0,
+        };
+        let too_dark = OKLCH {
+            l: -0.1,
+            c: 0.2,
+            h: 90.0,
+        };
+
+        assert_eq!(too_light.clamp_to_srgb(), rgb(255, 255, 255));
+        assert_eq!(too_dark.clamp_to_srgb().to_css(), "rgb(0, 0, 0)");
+    }
+
+    #[test]
+    fn can_construct_oklch() {
+        assert_eq!(
+            oklch(0.5, 0.1, 120.0),
+            OKLCH {
+                l: 0.5,
+                c: 0.1,
+                h: 120.0
+            }
+        );
+        assert_eq!(oklch(-0.5, 0.1, -90.0), oklch(0.0, 0.1, 270.0));
+        assert_eq!(oklch(2.0, -1.0, 720.0), oklch(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_non_finite_oklch() {
+        oklch(0.5, f32::NAN, 0.0);
+    }
+
+    #[test]
+    fn can_convert_oklch_to_rgb() {
+        assert_eq!(oklch(0.0, 0.0, 0.0).to_rgb(), rgb(0, 0, 0));
+
+        let red = OKLCH::from_rgb(rgb(255, 0, 0));
+        assert_eq!(oklch(red.l, red.c, red.h).to_rgb(), rgb(255, 0, 0));
+
+        // Out of gamut colors keep their lightness and hue, losing chroma instead.
+        let vivid = oklch(0.7, 0.4, 150.0);
+        let mapped = OKLCH::from_rgb(vivid.to_rgb());
+
+        assert!(!vivid.is_in_srgb_gamut());
+        assert!(mapped.c < vivid.c);
+        assert!((mapped.l - vivid.l).abs() < 0.01);
+        assert!((mapped.h - vivid.h).abs() < 1.0);
+    }
+}
@@ -0,0 +1,728 @@
+use super::{css_var, Angle, Color, Ratio, RGB, RGBA};
+use std::iter;
+
+// Colors whose channels are all within this distance of each other (roughly 10%)
+// are considered achromatic, since their hue is either undefined or invisible.
+const ACHROMATIC_CHROMA: u8 = 25;
+
+// Returns whether `color` is close enough to a grey that its hue is meaningless.
+pub(crate) fn is_achromatic(color: RGB) -> bool {
+    let RGB { r, g, b } = color;
+    let max = r.max(g).max(b).as_u8();
+    let min = r.min(g).min(b).as_u8();
+
+    max - min <= ACHROMATIC_CHROMA
+}
+
+/// Sorts a slice of colors in place so that they wrap around the color wheel,
+/// producing a "rainbow" arrangement.
+///
+/// Chromatic colors come first, ordered by their HSL hue starting at red (0°),
+/// with ties broken by lightness (darkest first). Achromatic colors (greys, and any
+/// color whose channels all lie within roughly 10% of each other) have no meaningful
+/// hue, so they are moved to the end and ordered from darkest to lightest.
+/// The sort is stable, so equal colors keep their relative order.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, sort_by_hue};
+///
+/// let mut colors = [rgb(255, 255, 255), rgb(0, 0, 255), rgb(0, 0, 0), rgb(255, 0, 0), rgb(0, 255, 0)];
+///
+/// sort_by_hue(&mut colors);
+///
+/// assert_eq!(colors, [rgb(255, 0, 0), rgb(0, 255, 0), rgb(0, 0, 255), rgb(0, 0, 0), rgb(255, 255, 255)]);
+/// ```
+pub fn sort_by_hue(colors: &mut [RGB]) {
+    colors.sort_by_key(|&color| {
+        let hsl = color.to_hsl();
+
+        if is_achromatic(color) {
+            (true, 0, hsl.l)
+        } else {
+            (false, hsl.h.degrees(), hsl.l)
+        }
+    });
+}
+
+/// Sorts a slice of colors in place by relative luminance, from darkest to lightest.
+///
+/// The luminance of each color is computed only once, using `RGB::luminance_sort_key`,
+/// rather than on every comparison, which matters when sorting large palettes.
+/// The sort is stable, so colors of equal luminance keep their relative order.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, sort_by_luminance};
+///
+/// let mut colors = [rgb(255, 255, 255), rgb(0, 0, 255), rgb(0, 0, 0), rgb(0, 255, 0)];
+///
+/// sort_by_luminance(&mut colors);
+///
+/// assert_eq!(colors, [rgb(0, 0, 0), rgb(0, 0, 255), rgb(0, 255, 0), rgb(255, 255, 255)]);
+/// ```
+pub fn sort_by_luminance(colors: &mut [RGB]) {
+    let mut keys: Vec<(f32, RGB)> = colors
+        .iter()
+        .map(|color| color.luminance_sort_key())
+        .collect();
+
+    // Luminances are always finite, so they are totally ordered.
+    keys.sort_by(|lhs, rhs| lhs.0.partial_cmp(&rhs.0).unwrap());
+
+    for (color, (_, sorted)) in colors.iter_mut().zip(keys) {
+        *color = sorted;
+    }
+}
+
+/// Returns the colors of `colors` with near-duplicates removed, where a color is a
+/// near-duplicate if it lies within `threshold` of a color that was already kept, as
+/// measured by `Color::delta_e`. A `threshold` of `2.3` removes the shades that the
+/// eye cannot tell apart.
+///
+/// The colors are kept greedily in input order, so the first occurrence of a shade
+/// survives and later, similar ones are dropped. Reordering the input can therefore
+/// change which representative survives, and even how many colors are kept.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, dedup_similar};
+///
+/// let colors = [rgb(255, 99, 71), rgb(0, 0, 255), rgb(254, 100, 71), rgb(0, 0, 250)];
+///
+/// assert_eq!(dedup_similar(&colors, 2.3), [rgb(255, 99, 71), rgb(0, 0, 255)]);
+/// ```
+pub fn dedup_similar(colors: &[RGB], threshold: f32) -> Vec<RGB> {
+    let mut kept: Vec<RGB> = Vec::new();
+
+    for &color in colors {
+        if !kept.iter().any(|&other| color.is_similar(other, threshold)) {
+            kept.push(color);
+        }
+    }
+
+    kept
+}
+
+// The hues that `warmest` and `coolest` rank colors against: orange and azure.
+const WARM_HUE: u16 = 30;
+const COOL_HUE: u16 = 210;
+
+/// Returns the warmest color of `colors`, i.e. the one whose HSL hue is closest to
+/// orange (`30°`), or `None` if there is no chromatic color. This is useful to pick an
+/// accent color from a palette.
+///
+/// Achromatic colors (greys, and any color whose channels all lie within roughly 10%
+/// of each other, as in `sort_by_hue`) have no meaningful hue, so they are excluded.
+/// Of two equally warm colors, the first one is returned.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, warmest};
+///
+/// let colors = [rgb(0, 0, 255), rgb(255, 0, 0), rgb(255, 165, 0), rgb(128, 128, 128)];
+///
+/// assert_eq!(warmest(&colors), Some(rgb(255, 165, 0)));
+/// assert_eq!(warmest(&[rgb(128, 128, 128)]), None);
+/// ```
+pub fn warmest(colors: &[RGB]) -> Option<RGB> {
+    closest_to_hue(colors, Angle::new(WARM_HUE))
+}
+
+/// Returns the coolest color of `colors`, i.e. the one whose HSL hue is closest to
+/// azure (`210°`), or `None` if there is no chromatic color. As with `warmest`,
+/// achromatic colors are excluded, and the first of two equally cool colors is returned.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, coolest};
+///
+/// let colors = [rgb(0, 0, 255), rgb(255, 0, 0), rgb(0, 128, 255), rgb(255, 255, 255)];
+///
+/// assert_eq!(coolest(&colors), Some(rgb(0, 128, 255)));
+/// assert_eq!(coolest(&[]), None);
+/// ```
+pub fn coolest(colors: &[RGB]) -> Option<RGB> {
+    closest_to_hue(colors, Angle::new(COOL_HUE))
+}
+
+// Returns the first chromatic color whose hue is closest to `hue`.
+fn closest_to_hue(colors: &[RGB], hue: Angle) -> Option<RGB> {
+    colors
+        .iter()
+        .filter(|&&color| !is_achromatic(color))
+        .fold(None, |closest: Option<(Angle, RGB)>, &color| {
+            let distance = color.hsl_hue().distance(hue);
+
+            match closest {
+                Some((closest_distance, _)) if closest_distance <= distance => closest,
+                _ => Some((distance, color)),
+            }
+        })
+        .map(|(_, color)| color)
+}
+
+/// Returns the average of `colors`, or `None` if there are no colors.
+///
+/// Each channel, including alpha, is the arithmetic mean of that channel across
+/// `colors`. This is a naive mean of the gamma-encoded sRGB values, so it is not
+/// perceptually uniform: averaging saturated complementary colors, for instance,
+/// gives a darker grey than the eye expects. For a perceptually correct result,
+/// average in a space like OKLab instead, e.g. by mixing with `mix_oklab`.
+///
+/// # Example
+/// ```
+/// use css_colors::{average, rgba};
+///
+/// let colors = [rgba(255, 0, 0, 1.0), rgba(0, 0, 255, 1.0), rgba(0, 255, 0, 1.0)];
+///
+/// assert_eq!(average(&colors), Some(rgba(85, 85, 85, 1.0)));
+/// assert_eq!(average(&[]), None);
+/// ```
+pub fn average(colors: &[RGBA]) -> Option<RGBA> {
+    colors.iter().sum::<ColorSum>().finish()
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+/// An accumulator for averaging a stream of colors, collected with `Iterator::sum`.
+///
+/// The channels are summed as floats, so any number of colors can be added without
+/// overflowing. Like `average`, `finish` computes a naive mean in sRGB.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, ColorSum};
+///
+/// let colors = vec![rgba(0, 0, 0, 1.0), rgba(255, 255, 255, 0.0)];
+/// let sum: ColorSum = colors.into_iter().sum();
+///
+/// assert_eq!(sum.finish(), Some(rgba(128, 128, 128, 0.5)));
+/// ```
+pub struct ColorSum {
+    r: f32,
+    g: f32,
+    b: f32,
+    a: f32,
+    count: usize,
+}
+
+impl ColorSum {
+    /// Adds `color` to the sum.
+    pub fn add(&mut self, color: RGBA) {
+        self.r += color.r.as_f32();
+        self.g += color.g.as_f32();
+        self.b += color.b.as_f32();
+        self.a += color.a.as_f32();
+        self.count += 1;
+    }
+
+    /// Returns the number of colors that have been added.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the colors that have been added, or `None` if there are none.
+    pub fn finish(&self) -> Option<RGBA> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let count = self.count as f32;
+
+        Some(RGBA {
+            r: Ratio::clamp(self.r / count),
+            g: Ratio::clamp(self.g / count),
+            b: Ratio::clamp(self.b / count),
+            a: Ratio::clamp(self.a / count),
+        })
+    }
+}
+
+impl iter::Sum<RGBA> for ColorSum {
+    fn sum<I: Iterator<Item = RGBA>>(colors: I) -> Self {
+        let mut sum = ColorSum::default();
+
+        for color in colors {
+            sum.add(color);
+        }
+
+        sum
+    }
+}
+
+impl<'a> iter::Sum<&'a RGBA> for ColorSum {
+    fn sum<I: Iterator<Item = &'a RGBA>>(colors: I) -> Self {
+        colors.cloned().sum()
+    }
+}
+
+/// Lazily lightens every color produced by `colors`, without collecting them.
+///
+/// # Example
+/// ```
+/// use css_colors::{lighten_iter, percent, rgb};
+///
+/// let palette = vec![rgb(0, 0, 0), rgb(128, 128, 128), rgb(250, 250, 250)];
+/// let lightened: Vec<_> = lighten_iter(palette, percent(10))
+///     .filter(|color| color.r != percent(100))
+///     .collect();
+///
+/// assert_eq!(lightened, [rgb(26, 26, 26), rgb(154, 154, 154)]);
+/// ```
+pub fn lighten_iter<I, T>(colors: I, amount: Ratio) -> impl Iterator<Item = T>
+where
+    I: IntoIterator<Item = T>,
+    T: Color,
+{
+    colors.into_iter().map(move |color| color.lighten(amount))
+}
+
+/// Lazily darkens every color produced by `colors`, without collecting them.
+///
+/// # Example
+/// ```
+/// use css_colors::{darken_iter, percent, rgb};
+///
+/// let palette = vec![rgb(0, 0, 0), rgb(128, 128, 128), rgb(255, 255, 255)];
+/// let darkened: Vec<_> = darken_iter(palette, percent(10)).collect();
+///
+/// assert_eq!(darkened, [rgb(0, 0, 0), rgb(102, 102, 102), rgb(229, 229, 229)]);
+/// ```
+pub fn darken_iter<I, T>(colors: I, amount: Ratio) -> impl Iterator<Item = T>
+where
+    I: IntoIterator<Item = T>,
+    T: Color,
+{
+    colors.into_iter().map(move |color| color.darken(amount))
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// An ordered collection of named colors, e.g. the color tokens of a design system.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, Palette};
+///
+/// let mut palette = Palette::new();
+/// palette.insert("brand", rgba(255, 99, 71, 1.0));
+/// palette.insert("overlay", rgba(0, 0, 0, 0.5));
+///
+/// assert_eq!(palette.get("brand"), Some(rgba(255, 99, 71, 1.0)));
+/// assert_eq!(
+///     palette.to_css_variables(),
+///     "--brand: rgba(255, 99, 71, 1.00);\n--overlay: rgba(0, 0, 0, 0.50);\n"
+/// );
+/// ```
+pub struct Palette {
+    entries: Vec<(String, RGBA)>,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Palette::default()
+    }
+
+    /// Returns the color with the given name, if there is one. As with `insert`, the
+    /// name may be given with or without a leading `--`.
+    pub fn get(&self, name: &str) -> Option<RGBA> {
+        let name = entry_name(name);
+
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == name)
+            .map(|&(_, color)| color)
+    }
+
+    /// Adds a named color to the end of the palette, returning the color it replaced
+    /// if the name was already taken. A replaced color keeps its original position.
+    ///
+    /// The name may be given with or without the leading `--` of a CSS custom property,
+    /// which is not stored: `insert("--brand", ..)` and `insert("brand", ..)` name the
+    /// same entry, as they do in `from_css_variables`.
+    pub fn insert<T: Color>(&mut self, name: &str, color: T) -> Option<RGBA> {
+        let name = entry_name(name);
+        let color = color.to_rgba();
+
+        match self.entries.iter_mut().find(|(entry, _)| entry == name) {
+            Some((_, existing)) => Some(::std::mem::replace(existing, color)),
+            None => {
+                self.entries.push((name.to_owned(), color));
+                None
+            }
+        }
+    }
+
+    /// Returns an iterator over the names and colors of the palette, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, RGBA)> {
+        self.entries
+            .iter()
+            .map(|(name, color)| (name.as_str(), *color))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Formats every color of the palette as a CSS custom property declaration,
+    /// one per line and in insertion order. Names that aren't valid CSS identifiers,
+    /// such as names with spaces, are sanitized as in `css_var`.
+    pub fn to_css_variables(&self) -> String {
+        self.iter()
+            .map(|(name, color)| css_var(name, color) + "\n")
+            .collect()
+    }
+
+    /// Returns a copy of the palette with every color lightened by `amount`, keeping
+    /// the names and order of the entries. See `Color::lighten`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{percent, rgb, rgba, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.insert("text", rgb(0, 0, 0));
+    ///
+    /// assert_eq!(palette.lighten_all(percent(20)).get("text"), Some(rgba(51, 51, 51, 1.0)));
+    /// ```
+    pub fn lighten_all(&self, amount: Ratio) -> Palette {
+        self.map(|color| color.lighten(amount))
+    }
+
+    /// Returns a copy of the palette with every color darkened by `amount`, keeping
+    /// the names and order of the entries. See `Color::darken`.
+    pub fn darken_all(&self, amount: Ratio) -> Palette {
+        self.map(|color| color.darken(amount))
+    }
+
+    /// Returns a copy of the palette with every color saturated by `amount`, keeping
+    /// the names and order of the entries. See `Color::saturate`.
+    pub fn saturate_all(&self, amount: Ratio) -> Palette {
+        self.map(|color| color.saturate(amount))
+    }
+
+    fn map<F: Fn(RGBA) -> RGBA>(&self, transform: F) -> Palette {
+        Palette {
+            entries: self
+                .entries
+                .iter()
+                .map(|(name, color)| (name.clone(), transform(*color)))
+                .collect(),
+        }
+    }
+}
+
+// Strips the leading `--` of a CSS custom property from a palette entry name.
+fn entry_name(name: &str) -> &str {
+    name.strip_prefix("--").unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        average, coolest, darken_iter, dedup_similar, hsl, lighten_iter, percent, rgb, rgba,
+        sort_by_hue, sort_by_luminance, warmest, Color, ColorSum, Palette, RGB, RGBA,
+    };
+
+    #[test]
+    fn dedups_similar_colors() {
+        let colors = [
+            rgb(255, 99, 71),
+            rgb(0, 0, 255),
+            rgb(254, 100, 71),
+            rgb(255, 99, 71),
+            rgb(0, 0, 250),
+            rgb(255, 255, 255),
+        ];
+
+        assert_eq!(
+            dedup_similar(&colors, 2.3),
+            [rgb(255, 99, 71), rgb(0, 0, 255), rgb(255, 255, 255)]
+        );
+        assert_eq!(dedup_similar(&colors, 0.0).len(), 5);
+        assert_eq!(dedup_similar(&colors, 1000.0), [rgb(255, 99, 71)]);
+        assert!(dedup_similar(&[], 2.3).is_empty());
+    }
+
+    #[test]
+    fn dedups_greedily_in_input_order() {
+        // Each step is just under the threshold, but the ends are not.
+        let (dark, middle, light) = (rgb(100, 100, 100), rgb(103, 103, 103), rgb(106, 106, 106));
+        let threshold = 1.5;
+
+        assert!(dark.is_similar(middle, threshold) && middle.is_similar(light, threshold));
+        assert!(!dark.is_similar(light, threshold));
+
+        assert_eq!(
+            dedup_similar(&[dark, middle, light], threshold),
+            [dark, light]
+        );
+        assert_eq!(dedup_similar(&[middle, dark, light], threshold), [middle]);
+    }
+
+    #[test]
+    fn sorts_by_luminance() {
+        let mut colors = [
+            rgb(255, 255, 0),
+            rgb(128, 128, 128),
+            rgb(255, 0, 0),
+            rgb(0, 0, 0),
+            rgb(0, 0, 255),
+        ];
+
+        sort_by_luminance(&mut colors);
+
+        assert_eq!(
+            colors,
+            [
+                rgb(0, 0, 0),
+                rgb(0, 0, 255),
+                rgb(255, 0, 0),
+                rgb(128, 128, 128),
+                rgb(255, 255, 0),
+            ]
+        );
+
+        let mut empty: [RGB; 0] = [];
+        sort_by_luminance(&mut empty);
+
+        assert_eq!(rgb(0, 0, 0).luminance_sort_key(), (0.0, rgb(0, 0, 0)));
+    }
+
+    #[test]
+    fn sorts_chromatic_colors_by_hue() {
+        let mut colors = [
+            rgb(238, 130, 238), // violet, 300°
+            rgb(255, 165, 0),   // orange, 39°
+            rgb(0, 128, 128),   // teal, 180°
+            rgb(220, 20, 60),   // crimson, 348°
+            rgb(255, 99, 71),   // tomato, 9°
+        ];
+
+        sort_by_hue(&mut colors);
+
+        assert_eq!(
+            colors,
+            [
+                rgb(255, 99, 71),
+                rgb(255, 165, 0),
+                rgb(0, 128, 128),
+                rgb(238, 130, 238),
+                rgb(220, 20, 60),
+            ]
+        );
+    }
+
+    #[test]
+    fn moves_achromatic_colors_to_the_end() {
+        let mut colors = [
+            rgb(200, 200, 200),
+            rgb(250, 250, 245), // nearly white, despite a high HSL saturation
+            rgb(30, 30, 30),
+            rgb(0, 0, 255),
+            rgb(128, 0, 0),
+            rgb(255, 0, 0),
+        ];
+
+        sort_by_hue(&mut colors);
+
+        assert_eq!(
+            colors,
+            [
+                rgb(128, 0, 0),
+                rgb(255, 0, 0),
+                rgb(0, 0, 255),
+                rgb(30, 30, 30),
+                rgb(200, 200, 200),
+                rgb(250, 250, 245),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_warmest_and_coolest_colors() {
+        let colors = [
+            rgb(220, 20, 60),   // crimson, 348°
+            rgb(0, 128, 128),   // teal, 180°
+            rgb(255, 99, 71),   // tomato, 9°
+            rgb(138, 43, 226),  // blue violet, 271°
+            rgb(250, 250, 245), // nearly white
+        ];
+
+        assert_eq!(warmest(&colors), Some(rgb(255, 99, 71)));
+        assert_eq!(coolest(&colors), Some(rgb(0, 128, 128)));
+
+        // Hues are compared along the shorter arc, so 348° is closer to 30° than 90° is.
+        assert_eq!(
+            warmest(&[rgb(128, 255, 0), rgb(220, 20, 60)]),
+            Some(rgb(220, 20, 60))
+        );
+
+        // Ties go to the first color.
+        assert_eq!(
+            warmest(&[rgb(255, 0, 0), rgb(255, 255, 0)]),
+            Some(rgb(255, 0, 0))
+        );
+
+        // Achromatic colors are never picked, even if they come first.
+        let greys = [rgb(0, 0, 0), rgb(128, 128, 128), rgb(255, 255, 255)];
+        assert_eq!(warmest(&greys), None);
+        assert_eq!(coolest(&greys), None);
+        assert_eq!(warmest(&[]), None);
+    }
+
+    #[test]
+    fn handles_empty_slices() {
+        let mut colors = [];
+
+        sort_by_hue(&mut colors);
+
+        assert!(colors.is_empty());
+    }
+
+    #[test]
+    fn can_transform_colors_lazily() {
+        let colors = [hsl(0, 100, 20), hsl(120, 100, 50), hsl(240, 100, 95)];
+
+        let lightened: Vec<_> = lighten_iter(colors.iter().cloned(), percent(10)).collect();
+        let darkened: Vec<_> = darken_iter(colors.iter().cloned(), percent(10)).collect();
+
+        assert_eq!(
+            lightened,
+            colors
+                .iter()
+                .map(|color| color.lighten(percent(10)))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(darkened[0].l.as_percentage(), 10);
+        assert_eq!(darken_iter(Vec::<::RGB>::new(), percent(10)).count(), 0);
+    }
+
+    #[test]
+    fn can_average_colors() {
+        assert_eq!(average(&[]), None);
+        assert_eq!(
+            average(&[rgba(255, 99, 71, 0.5)]),
+            Some(rgba(255, 99, 71, 0.5))
+        );
+        assert_eq!(
+            average(&[rgba(0, 0, 0, 1.0), rgba(255, 255, 255, 1.0)]),
+            Some(rgba(128, 128, 128, 1.0))
+        );
+        assert_eq!(
+            average(&[
+                rgba(10, 20, 30, 0.0),
+                rgba(20, 40, 60, 0.0),
+                rgba(30, 60, 90, 0.0)
+            ]),
+            Some(rgba(20, 40, 60, 0.0))
+        );
+    }
+
+    #[test]
+    fn can_sum_colors() {
+        let empty: ColorSum = Vec::<RGBA>::new().into_iter().sum();
+
+        assert_eq!(empty.count(), 0);
+        assert_eq!(empty.finish(), None);
+
+        let colors = [rgba(255, 0, 0, 1.0), rgba(0, 255, 0, 1.0)];
+        let by_value: ColorSum = colors.iter().cloned().sum();
+        let by_reference: ColorSum = colors.iter().sum();
+
+        assert_eq!(by_value, by_reference);
+        assert_eq!(by_value.count(), 2);
+        assert_eq!(by_value.finish(), Some(rgba(128, 128, 0, 1.0)));
+
+        // Summing many colors does not overflow.
+        let mut sum = ColorSum::default();
+        for _ in 0..100_000 {
+            sum.add(rgba(255, 255, 255, 1.0));
+        }
+        assert_eq!(sum.finish(), Some(rgba(255, 255, 255, 1.0)));
+    }
+
+    #[test]
+    fn can_manage_named_colors() {
+        let mut palette = Palette::new();
+
+        assert!(palette.is_empty());
+        assert_eq!(palette.get("brand"), None);
+
+        assert_eq!(palette.insert("brand", rgb(255, 99, 71)), None);
+        assert_eq!(palette.insert("accent", hsl(219, 79, 66)), None);
+        assert_eq!(
+            palette.insert("brand", rgba(0, 0, 0, 0.5)),
+            Some(rgba(255, 99, 71, 1.0))
+        );
+
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.get("brand"), Some(rgba(0, 0, 0, 0.5)));
+        assert_eq!(
+            palette.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["brand", "accent"]
+        );
+
+        // A leading `--` is not part of the name.
+        assert_eq!(
+            palette.insert("--brand", rgb(255, 99, 71)),
+            Some(rgba(0, 0, 0, 0.5))
+        );
+        assert_eq!(palette.get("--accent"), palette.get("accent"));
+        assert_eq!(palette.len(), 2);
+    }
+
+    #[test]
+    fn can_export_css_variables() {
+        let mut palette = Palette::new();
+
+        assert_eq!(palette.to_css_variables(), "");
+
+        palette.insert("--brand", rgb(255, 99, 71));
+        palette.insert("shadow", rgba(0, 0, 0, 0.25));
+
+        assert_eq!(palette.get("brand"), Some(rgba(255, 99, 71, 1.0)));
+        assert_eq!(
+            Palette::from_css_variables(&palette.to_css_variables()),
+            Ok(palette.clone())
+        );
+
+        assert_eq!(
+            palette.to_css_variables(),
+            "--brand: rgba(255, 99, 71, 1.00);\n--shadow: rgba(0, 0, 0, 0.25);\n"
+        );
+    }
+
+    #[test]
+    fn can_transform_whole_palette() {
+        let mut palette = Palette::new();
+        palette.insert("brand", hsl(9, 80, 50));
+        palette.insert("overlay", rgba(0, 0, 0, 0.5));
+
+        let lighter = palette.lighten_all(percent(10));
+        let names: Vec<&str> = lighter.iter().map(|(name, _)| name).collect();
+
+        assert_eq!(names, ["brand", "overlay"]);
+        assert_eq!(
+            lighter.get("brand"),
+            palette.get("brand").map(|color| color.lighten(percent(10)))
+        );
+        assert_eq!(lighter.get("overlay").unwrap().a, percent(50));
+
+        assert_eq!(
+            palette.darken_all(percent(10)).get("brand"),
+            palette.get("brand").map(|color| color.darken(percent(10)))
+        );
+        assert_eq!(
+            palette.saturate_all(percent(10)).get("brand"),
+            palette
+                .get("brand")
+                .map(|color| color.saturate(percent(10)))
+        );
+
+        assert!(Palette::new().lighten_all(percent(10)).is_empty());
+    }
+}
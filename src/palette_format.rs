@@ -0,0 +1,581 @@
+use super::lab::from_lab;
+use super::{Color, ColorError, CssColor, Palette, Ratio, RGB};
+
+// The block types of an Adobe Swatch Exchange file.
+const ASE_COLOR: u16 = 0x0001;
+const ASE_GROUP_START: u16 = 0xc001;
+const ASE_GROUP_END: u16 = 0xc002;
+
+impl Palette {
+    /// Reads a palette in the GIMP `.gpl` format, which GIMP, Inkscape, and Krita
+    /// (among others) use to store palettes.
+    ///
+    /// A `.gpl` file starts with a `GIMP Palette` line, optionally followed by `Name:`
+    /// and `Columns:` headers, which are ignored. Every other line is either a comment
+    /// starting with `#`, or a color as three `0-255` channels separated by whitespace,
+    /// followed by an optional name, which may contain spaces.
+    ///
+    /// The format has no alpha channel, so every color is opaque. Colors without a name
+    /// are named after their position among the colors, as `color-1`, `color-2`, etc.
+    /// As with `insert`, a color whose name is already taken replaces the earlier one.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgba, Palette};
+    ///
+    /// let palette = Palette::from_gpl("GIMP Palette\nName: Brand\n#\n255  99  71\tbrand\n  0   0 128\n").unwrap();
+    ///
+    /// assert_eq!(palette.get("brand"), Some(rgba(255, 99, 71, 1.0)));
+    /// assert_eq!(palette.get("color-2"), Some(rgba(0, 0, 128, 1.0)));
+    /// assert!(Palette::from_gpl("255 99 71 brand").is_err());
+    /// ```
+    pub fn from_gpl(input: &str) -> Result<Palette, ColorError> {
+        let mut lines = input.lines().enumerate();
+
+        let header = lines
+            .next()
+            .map(|(_, header)| header.trim_start_matches('\u{feff}'));
+
+        if header.map(str::trim) != Some("GIMP Palette") {
+            return Err(invalid("missing \"GIMP Palette\" header"));
+        }
+
+        let mut palette = Palette::new();
+        let mut count = 0;
+
+        for (index, line) in lines {
+            let line = line.trim();
+
+            if line.is_empty()
+                || line.starts_with('#')
+                || line.starts_with("Name:")
+                || line.starts_with("Columns:")
+            {
+                continue;
+            }
+
+            let invalid_line = || invalid(&format!("invalid color on line {}", index + 1));
+            let mut rest = line;
+            let mut channel = || -> Result<Ratio, ColorError> {
+                let (field, remainder) = next_field(rest);
+                rest = remainder;
+
+                field
+                    .parse::<u8>()
+                    .map(Ratio::from_u8)
+                    .map_err(|_| invalid_line())
+            };
+
+            let (r, g, b) = (channel()?, channel()?, channel()?);
+            count += 1;
+
+            palette.insert(&name_or_position(rest, count), RGB { r, g, b });
+        }
+
+        Ok(palette)
+    }
+
+    /// Writes the palette in the GIMP `.gpl` format. See `from_gpl`.
+    ///
+    /// Each color is written on its own line as `r g b`, padded as GIMP does, followed
+    /// by a tab and its name. The format has no alpha channel, so it is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, Palette};
+    ///
+    /// let mut palette = Palette::new();
+    /// palette.insert("brand", rgb(255, 99, 71));
+    ///
+    /// assert_eq!(palette.to_gpl(), "GIMP Palette\n#\n255  99  71\tbrand\n");
+    /// ```
+    pub fn to_gpl(&self) -> String {
+        let mut gpl = String::from("GIMP Palette\n#\n");
+
+        for (name, color) in self.iter() {
+            gpl += &format!(
+                "{:3} {:3} {:3}\t{}\n",
+                color.r.as_u8(),
+                color.g.as_u8(),
+                color.b.as_u8(),
+                name
+            );
+        }
+
+        gpl
+    }
+
+    /// Reads a palette in the binary Adobe Swatch Exchange (`.ase`) format, which
+    /// Photoshop, Illustrator, and InDesign use to share swatches.
+    ///
+    /// Colors in the RGB, Gray, CMYK, and Lab models are supported. CMYK colors are
+    /// converted naively, without a color profile, so they may not match what an Adobe
+    /// application shows. Groups are flattened into a single list of colors, and
+    /// whether a color is a global, spot, or process color is ignored. As in `from_gpl`,
+    /// unnamed colors are named after their position, and duplicate names replace
+    /// earlier colors. There is no support for writing `.ase` files.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgba, Palette};
+    ///
+    /// // The header and block count, then one color block: its type and length, the
+    /// // null-terminated UTF-16 name, the color model and value, and the color type.
+    /// let ase = b"ASEF\x00\x01\x00\x00\x00\x00\x00\x01\
+    ///     \x00\x01\x00\x00\x00\x14\
+    ///     \x00\x04\x00i\x00n\x00k\x00\x00\
+    ///     Gray\x00\x00\x00\x00\x00\x02";
+    ///
+    /// let palette = Palette::from_ase(ase).unwrap();
+    ///
+    /// assert_eq!(palette.get("ink"), Some(rgba(0, 0, 0, 1.0)));
+    /// assert!(Palette::from_ase(b"ASEF").is_err());
+    /// ```
+    pub fn from_ase(bytes: &[u8]) -> Result<Palette, ColorError> {
+        let mut reader = AseReader { bytes };
+
+        if reader.take(4)? != b"ASEF" {
+            return Err(invalid("missing \"ASEF\" signature"));
+        }
+
+        // The major and minor version, which are always 1.0.
+        reader.take(4)?;
+
+        let blocks = reader.u32()?;
+        let mut palette = Palette::new();
+        let mut count = 0;
+
+        for _ in 0..blocks {
+            let kind = reader.u16()?;
+            let length = reader.u32()? as usize;
+            let mut block = AseReader {
+                bytes: reader.take(length)?,
+            };
+
+            match kind {
+                ASE_COLOR => {
+                    let name = block.name()?;
+                    let color = block.color()?;
+                    count += 1;
+
+                    palette.insert(&name_or_position(&name, count), color);
+                }
+                ASE_GROUP_START | ASE_GROUP_END => {}
+                _ => return Err(invalid(&format!("unknown block type {:#06x}", kind))),
+            }
+        }
+
+        Ok(palette)
+    }
+
+    /// Reads a block of CSS custom property declarations, such as the output of
+    /// `to_css_variables`, e.g. `--brand: #ff6347; --ink: rgb(0, 0, 128);`.
+    ///
+    /// The declarations may be wrapped in a single rule, e.g. `:root { ... }`, whose
+    /// selector is ignored, and may contain `/* */` comments. Every declaration must
+    /// be a custom property holding a color that `CssColor` can parse, so a block that
+    /// also declares e.g. `--spacing: 4px` is rejected. The names are stored without
+    /// their leading `--`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgba, Palette};
+    ///
+    /// let palette = Palette::from_css_variables(":root {\n  --brand: #ff6347;\n  --overlay: rgba(0, 0, 0, 0.5);\n}").unwrap();
+    ///
+    /// assert_eq!(palette.get("brand"), Some(rgba(255, 99, 71, 1.0)));
+    /// assert_eq!(palette.get("overlay"), Some(rgba(0, 0, 0, 0.5)));
+    /// ```
+    pub fn from_css_variables(input: &str) -> Result<Palette, ColorError> {
+        let input = strip_comments(input);
+        let declarations = match (input.find('{'), input.rfind('}')) {
+            (Some(open), Some(close)) if open < close => &input[open + 1..close],
+            (None, None) => &input[..],
+            _ => return Err(invalid("unbalanced braces")),
+        };
+
+        let mut palette = Palette::new();
+
+        for declaration in declarations.split(';').map(str::trim) {
+            if declaration.is_empty() {
+                continue;
+            }
+
+            let colon = declaration
+                .find(':')
+                .ok_or_else(|| invalid(&format!("invalid declaration \"{}\"", declaration)))?;
+            let name = declaration[..colon].trim();
+            let name = name
+                .strip_prefix("--")
+                .ok_or_else(|| invalid(&format!("\"{}\" is not a custom property", name)))?;
+
+            let color = match declaration[colon + 1..].parse::<CssColor>()? {
+                CssColor::Rgb(color) => color.to_rgba(),
+                CssColor::Rgba(color) => color,
+                CssColor::Hsl(color) => color.to_rgba(),
+                CssColor::Hsla(color) => color.to_rgba(),
+            };
+
+            palette.insert(name, color);
+        }
+
+        Ok(palette)
+    }
+}
+
+fn invalid(reason: &str) -> ColorError {
+    ColorError::InvalidPalette(reason.to_owned())
+}
+
+// Splits the first whitespace-separated field off of `line`.
+fn next_field(line: &str) -> (&str, &str) {
+    let line = line.trim_start();
+    let end = line.find(char::is_whitespace).unwrap_or(line.len());
+
+    (&line[..end], &line[end..])
+}
+
+// Returns the trimmed `name`, or a name made from the 1-based `position` if it is empty.
+fn name_or_position(name: &str, position: usize) -> String {
+    let name = name.trim();
+
+    if name.is_empty() {
+        format!("color-{}", position)
+    } else {
+        name.to_owned()
+    }
+}
+
+fn strip_comments(input: &str) -> String {
+    let mut stripped = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+
+    stripped + rest
+}
+
+// Reads the big-endian values of an `.ase` file from the front of `bytes`.
+struct AseReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> AseReader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], ColorError> {
+        if self.bytes.len() < length {
+            return Err(invalid("unexpected end of file"));
+        }
+
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<u16, ColorError> {
+        let bytes = self.take(2)?;
+
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, ColorError> {
+        let bytes = self.take(4)?;
+
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f32, ColorError> {
+        self.u32().map(f32::from_bits)
+    }
+
+    // Reads a name, stored as its length in UTF-16 code units, followed by the
+    // null-terminated UTF-16 string.
+    fn name(&mut self) -> Result<String, ColorError> {
+        let length = self.u16()?;
+        let units = (0..length)
+            .map(|_| self.u16())
+            .collect::<Result<Vec<u16>, ColorError>>()?;
+
+        String::from_utf16(&units)
+            .map(|name| name.trim_end_matches('\0').to_owned())
+            .map_err(|_| invalid("invalid color name"))
+    }
+
+    fn color(&mut self) -> Result<RGB, ColorError> {
+        let model = self.take(4)?;
+        let mut values = |count: usize| {
+            (0..count)
+                .map(|_| self.f32())
+                .collect::<Result<Vec<f32>, ColorError>>()
+        };
+
+        let (r, g, b) = match model {
+            b"RGB " => {
+                let rgb = values(3)?;
+                (rgb[0], rgb[1], rgb[2])
+            }
+            b"Gray" => {
+                let gray = values(1)?[0];
+                (gray, gray, gray)
+            }
+            b"CMYK" => {
+                let cmyk = values(4)?;
+                let white = 1.0 - cmyk[3];
+                (
+                    (1.0 - cmyk[0]) * white,
+                    (1.0 - cmyk[1]) * white,
+                    (1.0 - cmyk[2]) * white,
+                )
+            }
+            b"LAB " => {
+                // The lightness is stored as a fraction of 100.
+                let lab = values(3)?;
+
+                if !lab.iter().all(|value| value.is_finite()) {
+                    return Err(invalid("invalid color value"));
+                }
+
+                return Ok(from_lab(lab[0] * 100.0, lab[1], lab[2]));
+            }
+            _ => {
+                return Err(invalid(&format!(
+                    "unsupported color model \"{}\"",
+                    String::from_utf8_lossy(model)
+                )))
+            }
+        };
+
+        // Non-finite values are rejected, rather than clamped into a color.
+        if !(r.is_finite() && g.is_finite() && b.is_finite()) {
+            return Err(invalid("invalid color value"));
+        }
+
+        Ok(RGB {
+            r: Ratio::clamp(r),
+            g: Ratio::clamp(g),
+            b: Ratio::clamp(b),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsl, rgb, rgba, Color, ColorError, Palette, RGBA};
+
+    fn colors(palette: &Palette) -> Vec<(&str, RGBA)> {
+        palette.iter().collect()
+    }
+
+    #[test]
+    fn can_read_gpl() {
+        let palette = Palette::from_gpl(
+            "GIMP Palette\r\nName: Brand colors\r\nColumns: 4\r\n#\r\n# A comment\r\n\
+             255  99  71\tbrand\r\n  0   0 128 deep navy\r\n\r\n128 128 128\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            colors(&palette),
+            [
+                ("brand", rgba(255, 99, 71, 1.0)),
+                ("deep navy", rgba(0, 0, 128, 1.0)),
+                ("color-3", rgba(128, 128, 128, 1.0)),
+            ]
+        );
+
+        assert!(Palette::from_gpl("GIMP Palette\n").unwrap().is_empty());
+        assert_eq!(
+            Palette::from_gpl("\u{feff}GIMP Palette\n0 0 0\n")
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_gpl() {
+        assert_eq!(
+            Palette::from_gpl(""),
+            Err(ColorError::InvalidPalette(
+                "missing \"GIMP Palette\" header".to_owned()
+            ))
+        );
+        assert_eq!(
+            Palette::from_gpl("GIMP Palette\n0 0 0\n256 0 0 too bright\n"),
+            Err(ColorError::InvalidPalette(
+                "invalid color on line 3".to_owned()
+            ))
+        );
+        assert!(Palette::from_gpl("GIMP Palette\n0 0\n").is_err());
+        assert!(Palette::from_gpl("GIMP Palette\nred green blue\n").is_err());
+    }
+
+    #[test]
+    fn round_trips_gpl() {
+        let mut palette = Palette::new();
+        palette.insert("brand", rgb(255, 99, 71));
+        palette.insert("deep navy", rgba(0, 0, 128, 0.5));
+
+        let gpl = palette.to_gpl();
+
+        assert_eq!(
+            gpl,
+            "GIMP Palette\n#\n255  99  71\tbrand\n  0   0 128\tdeep navy\n"
+        );
+        assert_eq!(
+            colors(&Palette::from_gpl(&gpl).unwrap()),
+            [
+                ("brand", rgba(255, 99, 71, 1.0)),
+                ("deep navy", rgba(0, 0, 128, 1.0)),
+            ]
+        );
+        assert_eq!(Palette::new().to_gpl(), "GIMP Palette\n#\n");
+    }
+
+    // Builds an `.ase` color block.
+    fn ase_color(name: &str, model: &[u8; 4], values: &[f32]) -> Vec<u8> {
+        let mut body = Vec::new();
+        let units: Vec<u16> = name.encode_utf16().chain(Some(0)).collect();
+
+        body.extend_from_slice(&(units.len() as u16).to_be_bytes());
+        for unit in units {
+            body.extend_from_slice(&unit.to_be_bytes());
+        }
+        body.extend_from_slice(model);
+        for value in values {
+            body.extend_from_slice(&value.to_bits().to_be_bytes());
+        }
+        body.extend_from_slice(&2u16.to_be_bytes());
+
+        let mut block = vec![0x00, 0x01];
+        block.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        block.extend(body);
+        block
+    }
+
+    fn ase_file(blocks: &[Vec<u8>]) -> Vec<u8> {
+        let mut file = b"ASEF\x00\x01\x00\x00".to_vec();
+
+        file.extend_from_slice(&(blocks.len() as u32).to_be_bytes());
+        for block in blocks {
+            file.extend_from_slice(block);
+        }
+        file
+    }
+
+    #[test]
+    fn can_read_ase() {
+        let mut group_start = vec![0xc0, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03];
+        group_start.extend_from_slice(&[0x00, 0x41, 0x00, 0x42, 0x00, 0x00]);
+        let group_end = vec![0xc0, 0x02, 0x00, 0x00, 0x00, 0x00];
+
+        let file = ase_file(&[
+            group_start,
+            ase_color("tomato", b"RGB ", &[1.0, 99.0 / 255.0, 71.0 / 255.0]),
+            ase_color("", b"Gray", &[0.5]),
+            group_end,
+            ase_color("cyan ink", b"CMYK", &[1.0, 0.0, 0.0, 0.0]),
+            ase_color("white", b"LAB ", &[1.0, 0.0, 0.0]),
+        ]);
+
+        assert_eq!(
+            colors(&Palette::from_ase(&file).unwrap()),
+            [
+                ("tomato", rgba(255, 99, 71, 1.0)),
+                ("color-2", rgba(128, 128, 128, 1.0)),
+                ("cyan ink", rgba(0, 255, 255, 1.0)),
+                ("white", rgba(255, 255, 255, 1.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_ase() {
+        assert!(Palette::from_ase(b"").is_err());
+        assert!(Palette::from_ase(b"GIMP Palette").is_err());
+
+        // The file promises more blocks than it contains.
+        let mut file = ase_file(&[ase_color("black", b"RGB ", &[0.0, 0.0, 0.0])]);
+        file[11] = 2;
+        assert_eq!(
+            Palette::from_ase(&file),
+            Err(ColorError::InvalidPalette(
+                "unexpected end of file".to_owned()
+            ))
+        );
+
+        let file = ase_file(&[ase_color("odd", b"HSV ", &[0.0, 0.0, 0.0])]);
+        assert!(Palette::from_ase(&file).is_err());
+
+        let file = ase_file(&[ase_color("nan", b"Gray", &[f32::NAN])]);
+        assert!(Palette::from_ase(&file).is_err());
+
+        let file = ase_file(&[ase_color("nan", b"LAB ", &[0.5, f32::NAN, 0.0])]);
+        assert_eq!(
+            Palette::from_ase(&file),
+            Err(ColorError::InvalidPalette("invalid color value".to_owned()))
+        );
+
+        let file = ase_file(&[ase_color("inf", b"LAB ", &[f32::INFINITY, 0.0, 0.0])]);
+        assert!(Palette::from_ase(&file).is_err());
+    }
+
+    #[test]
+    fn round_trips_css_variables() {
+        let mut palette = Palette::new();
+        palette.insert("brand", rgb(255, 99, 71));
+        palette.insert("overlay", rgba(0, 0, 0, 0.5));
+
+        assert_eq!(
+            Palette::from_css_variables(&palette.to_css_variables()),
+            Ok(palette)
+        );
+    }
+
+    #[test]
+    fn writes_gpl_names_as_valid_css_variables() {
+        let palette = Palette::from_gpl("GIMP Palette\n  0   0  80\tdeep navy\n").unwrap();
+        let css = palette.to_css_variables();
+
+        assert_eq!(css, "--deep-navy: rgba(0, 0, 80, 1.00);\n");
+        assert_eq!(
+            Palette::from_css_variables(&css).unwrap().get("deep-navy"),
+            palette.get("deep navy")
+        );
+    }
+
+    #[test]
+    fn can_read_css_variables() {
+        let palette = Palette::from_css_variables(
+            "/* Brand colors */\n:root {\n  --brand: Tomato; /* primary */\n  --accent: hsl(219, 79%, 66%)\n}\n",
+        )
+        .unwrap();
+
+        assert_eq!(palette.get("brand"), Some(rgba(255, 99, 71, 1.0)));
+        assert_eq!(palette.get("accent"), Some(hsl(219, 79, 66).to_rgba()));
+        assert_eq!(palette.len(), 2);
+
+        assert!(Palette::from_css_variables("").unwrap().is_empty());
+        assert!(Palette::from_css_variables(":root {}").unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_css_variables() {
+        assert_eq!(
+            Palette::from_css_variables("color: red;"),
+            Err(ColorError::InvalidPalette(
+                "\"color\" is not a custom property".to_owned()
+            ))
+        );
+        assert!(Palette::from_css_variables("--spacing: 4px;").is_err());
+        assert!(Palette::from_css_variables("--brand red;").is_err());
+        assert!(Palette::from_css_variables(":root { --brand: red;").is_err());
+    }
+}
@@ -0,0 +1,593 @@
+use super::{named, Angle, Color, ColorError, Ratio, HSL, HSLA, RGB, RGBA};
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// An enum to represent a color parsed from CSS, keeping the color model it was written in.
+///
+/// Hex notations and color keywords are parsed into the RGB family, using `Rgba`
+/// only when the input carried an alpha channel.
+pub enum CssColor {
+    Rgb(RGB),
+    Rgba(RGBA),
+    Hsl(HSL),
+    Hsla(HSLA),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// An enum to represent the notation a CSS color was written in.
+pub enum InputFormat {
+    /// `#rgb`
+    Hex3,
+
+    /// `#rgba`
+    Hex4,
+
+    /// `#rrggbb`
+    Hex6,
+
+    /// `#rrggbbaa`
+    Hex8,
+
+    /// `rgb(...)`
+    FunctionalRgb,
+
+    /// `rgba(...)`
+    FunctionalRgba,
+
+    /// `hsl(...)`
+    FunctionalHsl,
+
+    /// `hsla(...)`
+    FunctionalHsla,
+
+    /// A color keyword, such as `tomato`.
+    Named,
+}
+
+impl CssColor {
+    /// Parses a CSS color, also returning the notation it was written in.
+    ///
+    /// Supports hex notation (`#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`), the
+    /// `rgb()`, `rgba()`, `hsl()` and `hsla()` functions (with either the comma-separated
+    /// or the space-separated syntax), and the CSS color keywords. Parsing ignores
+    /// case and any surrounding whitespace.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, hsla, CssColor, InputFormat};
+    ///
+    /// assert_eq!(
+    ///     CssColor::parse_with_format("#ff6347"),
+    ///     Ok((CssColor::Rgb(rgb(255, 99, 71)), InputFormat::Hex6))
+    /// );
+    /// assert_eq!(
+    ///     CssColor::parse_with_format("hsla(9, 100%, 64%, 0.5)"),
+    ///     Ok((CssColor::Hsla(hsla(9, 100, 64, 0.5)), InputFormat::FunctionalHsla))
+    /// );
+    /// assert_eq!(
+    ///     CssColor::parse_with_format("Tomato"),
+    ///     Ok((CssColor::Rgb(rgb(255, 99, 71)), InputFormat::Named))
+    /// );
+    /// ```
+    pub fn parse_with_format(input: &str) -> Result<(CssColor, InputFormat), ColorError> {
+        parse(input, false)
+    }
+
+    /// Parses a CSS color like `str::parse::<CssColor>`, but also accepts the bare
+    /// numbers that some tools emit for the saturation and lightness of `hsl()` and
+    /// `hsla()`, treating them as percentages. `hsl(6, 93, 71)` is invalid CSS, and
+    /// is rejected by the default, strict parser, but matches the arguments of the
+    /// `hsl()` constructor. All other notations are parsed as strictly as ever.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsl, CssColor};
+    ///
+    /// assert_eq!(CssColor::parse_lenient("hsl(6, 93, 71)"), Ok(CssColor::Hsl(hsl(6, 93, 71))));
+    /// assert_eq!(CssColor::parse_lenient("hsl(6, 93%, 71%)"), Ok(CssColor::Hsl(hsl(6, 93, 71))));
+    /// assert!("hsl(6, 93, 71)".parse::<CssColor>().is_err());
+    /// ```
+    pub fn parse_lenient(input: &str) -> Result<CssColor, ColorError> {
+        parse(input, true).map(|(color, _)| color)
+    }
+}
+
+impl FromStr for CssColor {
+    type Err = ColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        CssColor::parse_with_format(input).map(|(color, _)| color)
+    }
+}
+
+/// Formats the color in the model it holds, so a color parsed from `hsl(...)` prints
+/// as `hsl(...)` again. Hex notations and keywords print in the `rgb()` notation they
+/// were parsed into. Formatter flags, such as the alternate `{:#}` form, are passed on.
+///
+/// # Example
+/// ```
+/// use css_colors::CssColor;
+///
+/// let color: CssColor = "hsl(9, 100%, 64%)".parse().unwrap();
+///
+/// assert_eq!(color.to_string(), "hsl(9, 100%, 64%)");
+/// ```
+impl fmt::Display for CssColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CssColor::Rgb(color) => fmt::Display::fmt(color, f),
+            CssColor::Rgba(color) => fmt::Display::fmt(color, f),
+            CssColor::Hsl(color) => fmt::Display::fmt(color, f),
+            CssColor::Hsla(color) => fmt::Display::fmt(color, f),
+        }
+    }
+}
+
+/// Parses any CSS color `CssColor` supports into its RGB representation.
+/// As with `to_rgb`, any alpha channel is dropped.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, RGB};
+///
+/// assert_eq!("hsl(9, 100%, 64%)".parse::<RGB>(), Ok(rgb(255, 99, 71)));
+/// assert!("tomatoes".parse::<RGB>().is_err());
+/// ```
+impl FromStr for RGB {
+    type Err = ColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(match CssColor::from_str(input)? {
+            CssColor::Rgb(color) => color,
+            CssColor::Rgba(color) => color.to_rgb(),
+            CssColor::Hsl(color) => color.to_rgb(),
+            CssColor::Hsla(color) => color.to_rgb(),
+        })
+    }
+}
+
+impl RGB {
+    /// Parses a CSS color like `str::parse::<RGB>`, but returns `fallback` instead
+    /// of an error when `input` is not a valid color. Any error is discarded, so
+    /// prefer `parse` wherever invalid input should be reported.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, RGB};
+    ///
+    /// assert_eq!(RGB::parse_or("red", rgb(0, 0, 0)), rgb(255, 0, 0));
+    /// assert_eq!(RGB::parse_or("not a color", rgb(0, 0, 0)), rgb(0, 0, 0));
+    /// ```
+    pub fn parse_or(input: &str, fallback: RGB) -> RGB {
+        input.parse().unwrap_or(fallback)
+    }
+}
+
+// Parses a CSS color, accepting bare numbers for the HSL saturation and lightness
+// when `lenient` is set.
+fn parse(input: &str, lenient: bool) -> Result<(CssColor, InputFormat), ColorError> {
+    let trimmed = input.trim();
+    let lowercase = trimmed.to_ascii_lowercase();
+    let invalid = || ColorError::InvalidSyntax(input.to_owned());
+
+    if let Some(digits) = lowercase.strip_prefix('#') {
+        return parse_hex(digits).ok_or_else(invalid);
+    }
+
+    if let Some(open) = lowercase.find('(') {
+        let arguments = lowercase[open + 1..]
+            .strip_suffix(')')
+            .ok_or_else(invalid)?;
+
+        return match lowercase[..open].trim_end() {
+            "rgb" => parse_rgb(arguments, InputFormat::FunctionalRgb),
+            "rgba" => parse_rgb(arguments, InputFormat::FunctionalRgba),
+            "hsl" => parse_hsl(arguments, InputFormat::FunctionalHsl, lenient),
+            "hsla" => parse_hsl(arguments, InputFormat::FunctionalHsla, lenient),
+            _ => None,
+        }
+        .ok_or_else(invalid);
+    }
+
+    if lowercase.is_empty() || !lowercase.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(invalid());
+    }
+
+    match named::lookup(&lowercase) {
+        Some(color) => Ok((CssColor::Rgb(color), InputFormat::Named)),
+        None => Err(ColorError::UnknownName {
+            name: trimmed.to_owned(),
+            did_you_mean: named::suggest(&lowercase),
+        }),
+    }
+}
+
+fn parse_hex(digits: &str) -> Option<(CssColor, InputFormat)> {
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    // Expands each digit of the short notations, e.g. `#f60` into `#ff6600`.
+    let expanded: String = match digits.len() {
+        3 | 4 => digits.chars().flat_map(|c| iter::repeat_n(c, 2)).collect(),
+        6 | 8 => digits.to_owned(),
+        _ => return None,
+    };
+
+    let byte = |index: usize| u8::from_str_radix(&expanded[index * 2..index * 2 + 2], 16).ok();
+    let (r, g, b) = (byte(0)?, byte(1)?, byte(2)?);
+    let rgb = RGB {
+        r: Ratio::from_u8(r),
+        g: Ratio::from_u8(g),
+        b: Ratio::from_u8(b),
+    };
+
+    match digits.len() {
+        3 => Some((CssColor::Rgb(rgb), InputFormat::Hex3)),
+        6 => Some((CssColor::Rgb(rgb), InputFormat::Hex6)),
+        length => {
+            let RGB { r, g, b } = rgb;
+            let color = RGBA {
+                r,
+                g,
+                b,
+                a: Ratio::from_u8(byte(3)?),
+            };
+            let format = if length == 4 {
+                InputFormat::Hex4
+            } else {
+                InputFormat::Hex8
+            };
+
+            Some((CssColor::Rgba(color), format))
+        }
+    }
+}
+
+// Splits the arguments of a color function into its three components and an
+// optional alpha, supporting both `a, b, c, alpha` and `a b c / alpha`.
+fn split_arguments(arguments: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    let mut components: Vec<&str>;
+    let alpha;
+
+    if arguments.contains(',') {
+        components = arguments.split(',').map(str::trim).collect();
+        alpha = if components.len() == 4 {
+            components.pop()
+        } else {
+            None
+        };
+    } else {
+        let mut halves = arguments.splitn(2, '/');
+
+        components = halves.next()?.split_whitespace().collect();
+        alpha = halves.next().map(str::trim);
+    }
+
+    if components.len() == 3 {
+        Some((components, alpha))
+    } else {
+        None
+    }
+}
+
+// Parses a number, or a percentage scaled so that 100% is equal to `max`.
+fn parse_number(value: &str, max: f32) -> Option<f32> {
+    let number = match value.strip_suffix('%') {
+        Some(percentage) => f32::from_str(percentage).ok()? / 100.0 * max,
+        None => f32::from_str(value).ok()?,
+    };
+
+    if number.is_finite() && (0.0..=max).contains(&number) {
+        Some(number)
+    } else {
+        None
+    }
+}
+
+fn parse_alpha(alpha: Option<&str>) -> Option<Option<Ratio>> {
+    match alpha {
+        Some(alpha) => Some(Some(Ratio::from_f32(parse_number(alpha, 1.0)?))),
+        None => Some(None),
+    }
+}
+
+fn parse_rgb(arguments: &str, format: InputFormat) -> Option<(CssColor, InputFormat)> {
+    let (components, alpha) = split_arguments(arguments)?;
+    let channel = |index: usize| -> Option<Ratio> {
+        let value: &str = components[index];
+
+        Some(Ratio::from_u8(parse_number(value, 255.0)?.round() as u8))
+    };
+
+    let (r, g, b) = (channel(0)?, channel(1)?, channel(2)?);
+    let color = match parse_alpha(alpha)? {
+        Some(a) => CssColor::Rgba(RGBA { r, g, b, a }),
+        None => CssColor::Rgb(RGB { r, g, b }),
+    };
+
+    Some((color, format))
+}
+
+fn parse_hsl(
+    arguments: &str,
+    format: InputFormat,
+    lenient: bool,
+) -> Option<(CssColor, InputFormat)> {
+    let (components, alpha) = split_arguments(arguments)?;
+
+    let h = Angle::from_str(components[0]).ok()?;
+
+    // Unlike the `hsl()` constructor, CSS requires the saturation and lightness to be
+    // percentages. Leniently, a bare number is read as a percentage instead.
+    let percentage = |value: &str| -> Option<Ratio> {
+        if value.strip_suffix('%').is_none() {
+            if !lenient {
+                return None;
+            }
+
+            return Some(Ratio::from_f32(parse_number(value, 100.0)? / 100.0));
+        }
+
+        Some(Ratio::from_f32(parse_number(value, 1.0)?))
+    };
+
+    let (s, l) = (percentage(components[1])?, percentage(components[2])?);
+    let color = match parse_alpha(alpha)? {
+        Some(a) => CssColor::Hsla(HSLA { h, s, l, a }),
+        None => CssColor::Hsl(HSL { h, s, l }),
+    };
+
+    Some((color, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsl, hsla, rgb, rgba, ColorError, CssColor, InputFormat, RGB};
+
+    fn parse(input: &str) -> (CssColor, InputFormat) {
+        CssColor::parse_with_format(input).unwrap()
+    }
+
+    #[test]
+    fn displays_in_the_parsed_model() {
+        for &input in &[
+            "rgb(255, 99, 71)",
+            "rgba(255, 99, 71, 0.50)",
+            "hsl(9, 100%, 64%)",
+            "hsla(9, 100%, 64%, 0.50)",
+        ] {
+            assert_eq!(input.parse::<CssColor>().unwrap().to_string(), input);
+        }
+
+        assert_eq!(
+            CssColor::Rgb(rgb(255, 99, 71)).to_string(),
+            "rgb(255, 99, 71)"
+        );
+        assert_eq!(
+            CssColor::Hsla(hsla(9, 100, 64, 0.5)).to_string(),
+            "hsla(9, 100%, 64%, 0.50)"
+        );
+        assert_eq!(parse("#ff6347").0.to_string(), "rgb(255, 99, 71)");
+        assert_eq!(
+            format!("{:#}", CssColor::Hsl(hsl(9, 100, 64))),
+            format!("{:#}", hsl(9, 100, 64))
+        );
+    }
+
+    #[test]
+    fn detects_hex_formats() {
+        assert_eq!(
+            parse("#f60"),
+            (CssColor::Rgb(rgb(255, 102, 0)), InputFormat::Hex3)
+        );
+        assert_eq!(
+            parse("#f600"),
+            (CssColor::Rgba(rgba(255, 102, 0, 0.0)), InputFormat::Hex4)
+        );
+        assert_eq!(
+            parse("#FF6347"),
+            (CssColor::Rgb(rgb(255, 99, 71)), InputFormat::Hex6)
+        );
+        assert_eq!(
+            parse("#ff634780"),
+            (CssColor::Rgba(rgba(255, 99, 71, 0.5)), InputFormat::Hex8)
+        );
+    }
+
+    #[test]
+    fn detects_functional_rgb_formats() {
+        assert_eq!(
+            parse("rgb(255, 99, 71)"),
+            (CssColor::Rgb(rgb(255, 99, 71)), InputFormat::FunctionalRgb)
+        );
+        assert_eq!(
+            parse("rgb(255 99 71 / 50%)"),
+            (
+                CssColor::Rgba(rgba(255, 99, 71, 0.5)),
+                InputFormat::FunctionalRgb
+            )
+        );
+        assert_eq!(
+            parse("rgba(255, 99, 71, 0.5)"),
+            (
+                CssColor::Rgba(rgba(255, 99, 71, 0.5)),
+                InputFormat::FunctionalRgba
+            )
+        );
+        assert_eq!(
+            parse("RGBA(100%, 0%, 50%)"),
+            (CssColor::Rgb(rgb(255, 0, 128)), InputFormat::FunctionalRgba)
+        );
+    }
+
+    #[test]
+    fn detects_functional_hsl_formats() {
+        assert_eq!(
+            parse("hsl(9, 100%, 64%)"),
+            (CssColor::Hsl(hsl(9, 100, 64)), InputFormat::FunctionalHsl)
+        );
+        assert_eq!(
+            parse("hsl(369deg 100% 64%)"),
+            (CssColor::Hsl(hsl(9, 100, 64)), InputFormat::FunctionalHsl)
+        );
+        assert_eq!(
+            parse("hsl(0.5turn 100% 64%)"),
+            (CssColor::Hsl(hsl(180, 100, 64)), InputFormat::FunctionalHsl)
+        );
+        assert_eq!(
+            parse("hsl(200grad, 100%, 64%)"),
+            (CssColor::Hsl(hsl(180, 100, 64)), InputFormat::FunctionalHsl)
+        );
+        assert_eq!(
+            parse("hsla(-351, 100%, 64%, 0.5)"),
+            (
+                CssColor::Hsla(hsla(9, 100, 64, 0.5)),
+                InputFormat::FunctionalHsla
+            )
+        );
+    }
+
+    #[test]
+    fn parses_bare_hsl_percentages_leniently() {
+        assert_eq!(
+            CssColor::parse_lenient("hsl(6, 93, 71)"),
+            Ok(CssColor::Hsl(hsl(6, 93, 71)))
+        );
+        assert_eq!(
+            CssColor::parse_lenient("HSLA(6 93 71 / 0.5)"),
+            Ok(CssColor::Hsla(hsla(6, 93, 71, 0.5)))
+        );
+        assert_eq!(
+            CssColor::parse_lenient("hsl(6, 93%, 71)"),
+            Ok(CssColor::Hsl(hsl(6, 93, 71)))
+        );
+        assert_eq!(
+            CssColor::parse_lenient("hsl(6, 93%, 71%)"),
+            "hsl(6, 93%, 71%)".parse()
+        );
+        assert_eq!(
+            CssColor::parse_lenient("tomato"),
+            Ok(CssColor::Rgb(rgb(255, 99, 71)))
+        );
+    }
+
+    #[test]
+    fn rejects_bare_hsl_percentages_strictly() {
+        for input in &["hsl(6, 93, 71)", "hsla(6, 93%, 71, 0.5)", "hsl(6 93 71%)"] {
+            assert_eq!(
+                input.parse::<CssColor>(),
+                Err(ColorError::InvalidSyntax(input.to_string()))
+            );
+        }
+
+        // Leniency does not extend to values that are out of range.
+        assert!(CssColor::parse_lenient("hsl(6, 101, 71)").is_err());
+        assert!(CssColor::parse_lenient("hsl(6, -1, 71)").is_err());
+        assert!(CssColor::parse_lenient("hsl(6, 93)").is_err());
+    }
+
+    #[test]
+    fn detects_named_format() {
+        assert_eq!(
+            parse("  tomato "),
+            (CssColor::Rgb(rgb(255, 99, 71)), InputFormat::Named)
+        );
+        assert_eq!(
+            parse("DarkSlateGrey"),
+            (CssColor::Rgb(rgb(47, 79, 79)), InputFormat::Named)
+        );
+    }
+
+    #[test]
+    fn can_parse_from_str() {
+        assert_eq!("#ff6347".parse(), Ok(CssColor::Rgb(rgb(255, 99, 71))));
+        assert_eq!(
+            "hsl(9, 100%, 64%)".parse(),
+            Ok(CssColor::Hsl(hsl(9, 100, 64)))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        for input in &[
+            "",
+            "#",
+            "#ff63a",
+            "#ff634",
+            "#gg6347",
+            "rgb(255, 99)",
+            "rgb(256, 99, 71)",
+            "rgb(255, 99, 71, 1.5)",
+            "rgb(255, 99, 71",
+            "rgb(255, 99, 71, 0.5, 1)",
+            "hsl(9, 100, 64)",
+            "hsl(9, 101%, 64%)",
+            "cmyk(0, 0, 0, 0)",
+            "light blue",
+        ] {
+            assert_eq!(
+                CssColor::parse_with_format(input),
+                Err(ColorError::InvalidSyntax(input.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert_eq!(
+            CssColor::parse_with_format("tomatoes"),
+            Err(ColorError::UnknownName {
+                name: "tomatoes".to_owned(),
+                did_you_mean: Some("tomato"),
+            })
+        );
+        assert_eq!(
+            CssColor::parse_with_format("CornflowerBleu"),
+            Err(ColorError::UnknownName {
+                name: "CornflowerBleu".to_owned(),
+                did_you_mean: Some("cornflowerblue"),
+            })
+        );
+        assert_eq!(
+            CssColor::parse_with_format("chartreux"),
+            Err(ColorError::UnknownName {
+                name: "chartreux".to_owned(),
+                did_you_mean: Some("chartreuse"),
+            })
+        );
+        assert_eq!(
+            CssColor::parse_with_format("tomatoo")
+                .unwrap_err()
+                .to_string(),
+            "unknown color name \"tomatoo\", did you mean \"tomato\"?"
+        );
+        assert_eq!(
+            CssColor::parse_with_format("xyzzy"),
+            Err(ColorError::UnknownName {
+                name: "xyzzy".to_owned(),
+                did_you_mean: None,
+            })
+        );
+    }
+
+    #[test]
+    fn can_parse_rgb() {
+        assert_eq!("#ff6347".parse(), Ok(rgb(255, 99, 71)));
+        assert_eq!("rgba(255, 99, 71, 0.5)".parse(), Ok(rgb(255, 99, 71)));
+        assert_eq!("hsla(9, 100%, 64%, 0.5)".parse(), Ok(rgb(255, 99, 71)));
+        assert_eq!(
+            "tomatoes".parse::<RGB>(),
+            Err(ColorError::UnknownName {
+                name: "tomatoes".to_owned(),
+                did_you_mean: Some("tomato"),
+            })
+        );
+
+        assert_eq!(RGB::parse_or(" Tomato ", rgb(0, 0, 0)), rgb(255, 99, 71));
+        assert_eq!(RGB::parse_or("", rgb(1, 2, 3)), rgb(1, 2, 3));
+        assert_eq!(RGB::parse_or("#ff63", rgb(1, 2, 3)), rgb(255, 255, 102));
+    }
+}
@@ -1,3 +1,5 @@
+use super::ColorError;
+use std::convert::TryFrom;
 use std::fmt;
 use std::ops;
 
@@ -29,7 +31,22 @@ impl Ratio {
         Ratio::from_f32(percentage as f32 / 100.0)
     }
 
-    pub fn from_u8(value: u8) -> Self {
+    /// Constructs a ratio from its raw `0-255` representation, so `255` is 100%.
+    /// To construct a ratio from a `0-100` percentage, use `percent` or `Ratio::try_from`.
+    pub const fn from_u8(value: u8) -> Self {
+        Ratio(value)
+    }
+
+    /// Constructs a ratio from its raw `0-255` representation. This is the same as
+    /// `from_u8`, but makes the intent clear when reloading a value saved with `to_raw`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::Ratio;
+    ///
+    /// assert_eq!(Ratio::from_raw(128), Ratio::from_u8(128));
+    /// ```
+    pub const fn from_raw(value: u8) -> Self {
         Ratio(value)
     }
 
@@ -40,6 +57,31 @@ impl Ratio {
         Ratio((float * 255.0).round() as u8)
     }
 
+    /// Constructs a ratio from a float, clamping values that fall outside of
+    /// the `0.0-1.0` range instead of panicking. This is the same clamping used
+    /// by the arithmetic operators on `Ratio`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{percent, Ratio};
+    ///
+    /// assert_eq!(Ratio::clamp(0.5), Ratio::from_f32(0.5));
+    /// assert_eq!(Ratio::clamp(1.5), percent(100));
+    /// assert_eq!(Ratio::clamp(-0.5), percent(0));
+    /// ```
+    pub fn clamp(value: f32) -> Self {
+        if value > 1.0 {
+            Ratio::from_f32(1.0)
+        } else if (0.0..=1.0).contains(&value) {
+            Ratio::from_f32(value)
+        } else {
+            Ratio::from_f32(0.0)
+        }
+    }
+
+    /// Returns the ratio as a whole percentage. Since a ratio is stored as one of
+    /// 256 steps, several ratios round to the same percentage, so this is lossy:
+    /// use `to_raw` to save a ratio exactly.
     pub fn as_percentage(self) -> u8 {
         (self.0 as f32 / 255.0 * 100.0).round() as u8
     }
@@ -48,9 +90,149 @@ impl Ratio {
         self.0
     }
 
+    /// Returns the raw `0-255` representation of the ratio. Unlike `as_percentage`
+    /// or `as_f32`, this is lossless: `Ratio::from_raw(ratio.to_raw())` always
+    /// equals `ratio`, so it is the representation to use for byte-exact storage.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{percent, Ratio};
+    ///
+    /// let ratio = Ratio::from_f32(0.333);
+    ///
+    /// assert_eq!(Ratio::from_raw(ratio.to_raw()), ratio);
+    /// assert_ne!(percent(ratio.as_percentage()), ratio);
+    /// ```
+    pub const fn to_raw(self) -> u8 {
+        self.0
+    }
+
     pub fn as_f32(self) -> f32 {
         self.0 as f32 / 255.0
     }
+
+    /// Formats the ratio as a percentage with `decimals` digits after the decimal point,
+    /// computed from the raw `0-255` value. Unlike `Display`, which rounds to a whole
+    /// percentage, this shows where a ratio actually lies between two percentages.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{percent, Ratio};
+    ///
+    /// let ratio = Ratio::from_u8(237);
+    ///
+    /// assert_eq!(ratio.to_string(), "93%");
+    /// assert_eq!(ratio.to_string_precise(2), "92.94%");
+    /// assert_eq!(percent(50).to_string_precise(1), "50.2%");
+    /// ```
+    pub fn to_string_precise(self, decimals: usize) -> String {
+        format!("{:.*}%", decimals, self.0 as f32 / 255.0 * 100.0)
+    }
+
+    /// Returns the smaller of `self` and `other`, comparing their raw `0-255` values.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::percent;
+    ///
+    /// assert_eq!(percent(30).min(percent(70)), percent(30));
+    /// ```
+    pub fn min(self, other: Ratio) -> Ratio {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of `self` and `other`, comparing their raw `0-255` values.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::percent;
+    ///
+    /// assert_eq!(percent(30).max(percent(70)), percent(70));
+    /// ```
+    pub fn max(self, other: Ratio) -> Ratio {
+        Ord::max(self, other)
+    }
+
+    /// Restricts `self` to the range between `lo` and `hi`, inclusive, comparing their
+    /// raw `0-255` values. This keeps a channel within a sub-range, e.g. never letting
+    /// a lightness exceed 90%. It is named to avoid clashing with `Ratio::clamp`, which
+    /// constructs a ratio from a float.
+    ///
+    /// Panics if `lo` is greater than `hi`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::percent;
+    ///
+    /// assert_eq!(percent(95).clamp_between(percent(10), percent(90)), percent(90));
+    /// assert_eq!(percent(5).clamp_between(percent(10), percent(90)), percent(10));
+    /// assert_eq!(percent(50).clamp_between(percent(10), percent(90)), percent(50));
+    /// ```
+    pub fn clamp_between(self, lo: Ratio, hi: Ratio) -> Ratio {
+        assert!(lo <= hi, "invalid range for Ratio::clamp_between");
+
+        self.max(lo).min(hi)
+    }
+}
+
+/// Converts a whole percentage between `0-100` into a ratio, like `percent` but
+/// returning an error instead of panicking on values above `100`.
+///
+/// Note that the `u8` is read as a percentage, on a `0-100` scale. To construct a
+/// ratio from its raw `0-255` representation instead, use `Ratio::from_u8`.
+///
+/// # Example
+/// ```
+/// use css_colors::{percent, ColorError, Ratio};
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(Ratio::try_from(50), Ok(percent(50)));
+/// assert_eq!(Ratio::try_from(100), Ok(Ratio::from_u8(255)));
+/// assert_eq!(Ratio::try_from(255), Err(ColorError::InvalidPercentage(255)));
+/// ```
+impl TryFrom<u8> for Ratio {
+    type Error = ColorError;
+
+    fn try_from(percentage: u8) -> Result<Self, Self::Error> {
+        if percentage > 100 {
+            return Err(ColorError::InvalidPercentage(percentage));
+        }
+
+        Ok(Ratio::from_percentage(percentage))
+    }
+}
+
+/// Converts a ratio into a float between `0.0-1.0`, as with `Ratio::as_f32`.
+///
+/// # Example
+/// ```
+/// use css_colors::percent;
+///
+/// let alpha: f32 = percent(100).into();
+///
+/// assert_eq!(alpha, 1.0);
+/// ```
+impl From<Ratio> for f32 {
+    fn from(ratio: Ratio) -> f32 {
+        ratio.as_f32()
+    }
+}
+
+/// Converts a ratio into its raw `0-255` representation, as with `Ratio::to_raw`.
+/// Note that this is not a percentage: `percent(100)` converts to `255`.
+///
+/// # Example
+/// ```
+/// use css_colors::percent;
+///
+/// let raw: u8 = percent(100).into();
+///
+/// assert_eq!(raw, 255);
+/// ```
+impl From<Ratio> for u8 {
+    fn from(ratio: Ratio) -> u8 {
+        ratio.to_raw()
+    }
 }
 
 impl fmt::Display for Ratio {
@@ -63,7 +245,7 @@ impl ops::Add for Ratio {
     type Output = Ratio;
 
     fn add(self, other: Ratio) -> Ratio {
-        clamp_ratio(self.as_f32() + other.as_f32())
+        Ratio::clamp(self.as_f32() + other.as_f32())
     }
 }
 
@@ -71,7 +253,7 @@ impl ops::Sub for Ratio {
     type Output = Ratio;
 
     fn sub(self, other: Ratio) -> Ratio {
-        clamp_ratio(self.as_f32() - other.as_f32())
+        Ratio::clamp(self.as_f32() - other.as_f32())
     }
 }
 
@@ -79,7 +261,7 @@ impl ops::Mul for Ratio {
     type Output = Ratio;
 
     fn mul(self, other: Ratio) -> Ratio {
-        clamp_ratio(self.as_f32() * other.as_f32())
+        Ratio::clamp(self.as_f32() * other.as_f32())
     }
 }
 
@@ -87,24 +269,33 @@ impl ops::Div for Ratio {
     type Output = Ratio;
 
     fn div(self, other: Ratio) -> Ratio {
-        clamp_ratio(self.as_f32() / other.as_f32())
-    }
-}
-
-// A function to clamp the value of a Ratio to fall between [0.0 - 1.0].
-fn clamp_ratio(value: f32) -> Ratio {
-    if value > 1.0 {
-        Ratio::from_f32(1.0)
-    } else if value >= 0.0 && value <= 1.0 {
-        Ratio::from_f32(value)
-    } else {
-        Ratio::from_f32(0.0)
+        Ratio::clamp(self.as_f32() / other.as_f32())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use Ratio;
+    use std::convert::TryFrom;
+    use {percent, ColorError, Ratio};
+
+    #[test]
+    fn can_compare_ratios() {
+        let (lo, hi) = (Ratio::from_u8(25), Ratio::from_u8(230));
+
+        assert_eq!(lo.min(hi), lo);
+        assert_eq!(hi.min(lo), lo);
+        assert_eq!(lo.max(hi), hi);
+        assert_eq!(Ratio::from_u8(24).clamp_between(lo, hi), lo);
+        assert_eq!(Ratio::from_u8(231).clamp_between(lo, hi), hi);
+        assert_eq!(percent(50).clamp_between(lo, hi), percent(50));
+        assert_eq!(percent(50).clamp_between(lo, lo), lo);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid range for Ratio::clamp_between")]
+    fn panics_on_an_inverted_range() {
+        percent(50).clamp_between(percent(90), percent(10));
+    }
 
     #[test]
     #[should_panic]
@@ -150,6 +341,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn can_clamp_f32_directly() {
+        assert_eq!(Ratio::clamp(0.25), Ratio::from_f32(0.25));
+        assert_eq!(Ratio::clamp(1.01), Ratio::from_f32(1.0));
+        assert_eq!(Ratio::clamp(-0.01), Ratio::from_f32(0.0));
+        assert_eq!(Ratio::clamp(f32::NAN), Ratio::from_f32(0.0));
+    }
+
+    #[test]
+    fn round_trips_raw_values() {
+        for value in 0..=255 {
+            let ratio = Ratio::from_raw(value);
+
+            assert_eq!(ratio.to_raw(), value);
+            assert_eq!(Ratio::from_raw(ratio.to_raw()), ratio);
+        }
+
+        // Only 101 of the 256 ratios survive a round trip through a percentage.
+        let lossless = (0..=255)
+            .map(Ratio::from_raw)
+            .filter(|&ratio| Ratio::from_percentage(ratio.as_percentage()) == ratio)
+            .count();
+        assert_eq!(lossless, 101);
+    }
+
     #[test]
     fn adds_percentage() {
         let a = Ratio::from_percentage(55);
@@ -247,4 +463,36 @@ mod tests {
         assert_eq!(a / c, Ratio::from_f32(0.25));
         assert_eq!(b / c, Ratio::from_f32(0.5));
     }
+
+    #[test]
+    fn formats_with_precision() {
+        assert_eq!(Ratio::from_u8(0).to_string_precise(2), "0.00%");
+        assert_eq!(Ratio::from_u8(255).to_string_precise(2), "100.00%");
+        assert_eq!(Ratio::from_u8(1).to_string_precise(3), "0.392%");
+        assert_eq!(Ratio::from_u8(237).to_string_precise(0), "93%");
+        assert_eq!(Ratio::from_u8(237).to_string(), "93%");
+    }
+
+    #[test]
+    fn converts_percentages_fallibly() {
+        assert_eq!(Ratio::try_from(0), Ok(Ratio::from_u8(0)));
+        assert_eq!(Ratio::try_from(25), Ok(Ratio::from_percentage(25)));
+        assert_eq!(Ratio::try_from(100), Ok(Ratio::from_u8(255)));
+        assert_eq!(
+            Ratio::try_from(101),
+            Err(ColorError::InvalidPercentage(101))
+        );
+
+        // Unlike `from_u8`, the input is a percentage rather than a raw value.
+        assert_ne!(Ratio::try_from(50), Ok(Ratio::from_u8(50)));
+    }
+
+    #[test]
+    fn converts_into_numbers() {
+        let ratio = Ratio::from_u8(51);
+
+        assert_eq!(f32::from(ratio), 0.2);
+        assert_eq!(u8::from(ratio), 51);
+        assert_eq!(u8::from(Ratio::from_percentage(50)), 128);
+    }
 }
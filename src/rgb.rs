@@ -1,6 +1,37 @@
-use super::{deg, percent, Angle, Color, Ratio, HSL, HSLA};
+use super::model;
+use super::oklch;
+use super::oklch::from_linear;
+use super::{deg, percent, Angle, Color, ColorError, Ratio, HSL, HSLA};
 use std::fmt;
 
+// How close `w * a` in `RGBA::mix` may get to -1 before the alpha difference is ignored.
+// Both come from `u8` ratios, so `w * a` is either exactly -1 or at least ~0.008 away
+// from it: no reachable input lands in between, and the tolerance is defensive only.
+const MIX_EPSILON: f32 = 0.000_001;
+
+// The xterm defaults for the 16 system colors of the 256-color palette.
+const ANSI_SYSTEM_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+// The levels of each channel in the 6×6×6 color cube of the 256-color palette.
+const ANSI_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
 /// Constructs a RGB Color from numerical values, similar to the
 /// [`rgb` function](css-rgb) in CSS.
 ///
@@ -14,7 +45,7 @@ use std::fmt;
 /// ```
 ///
 /// [css-rgb]: https://www.w3.org/TR/css-color-3/#rgb-color
-pub fn rgb(r: u8, g: u8, b: u8) -> RGB {
+pub const fn rgb(r: u8, g: u8, b: u8) -> RGB {
     RGB {
         r: Ratio::from_u8(r),
         g: Ratio::from_u8(g),
@@ -25,8 +56,10 @@ pub fn rgb(r: u8, g: u8, b: u8) -> RGB {
 /// Constructs a RGB Color from numerical values, similar to the
 /// [`rgba` function](css-rgba) in CSS.
 ///
-/// The alpha value is expressed as a float. Values outside of the
-/// 0.0-1.0 range will cause a panic.
+/// The alpha value is expressed as a float between `0.0` (transparent) and
+/// `1.0` (opaque), not as a `0-255` channel like `r`, `g`, and `b`. Values
+/// outside of the 0.0-1.0 range will cause a panic; use `rgba_checked` when the
+/// alpha is not a literal. To pass the alpha as a `0-255` channel, use `rgba_u8`.
 ///
 /// # Example
 /// ```
@@ -47,6 +80,70 @@ pub fn rgba(r: u8, g: u8, b: u8, a: f32) -> RGBA {
     }
 }
 
+/// Constructs a RGB Color like `rgba`, but returns an error instead of panicking
+/// when the alpha value falls outside of the 0.0-1.0 range (or is `NaN`). This suits
+/// alphas that come from user input or from a computation, which may overshoot the
+/// range by a little; clamp them first with `Ratio::clamp` if that is acceptable.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, rgba_checked, ColorError};
+///
+/// assert_eq!(rgba_checked(250, 128, 114, 0.5), Ok(rgba(250, 128, 114, 0.5)));
+/// assert_eq!(rgba_checked(250, 128, 114, 1.01), Err(ColorError::InvalidAlpha(1.01)));
+/// ```
+pub fn rgba_checked(r: u8, g: u8, b: u8, a: f32) -> Result<RGBA, ColorError> {
+    if !(0.0..=1.0).contains(&a) {
+        return Err(ColorError::InvalidAlpha(a));
+    }
+
+    Ok(rgba(r, g, b, a))
+}
+
+/// Constructs a RGB Color from numerical values, with the alpha value
+/// expressed as a `0-255` channel like `r`, `g`, and `b`, where `255` is opaque.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, rgba_u8};
+///
+/// assert_eq!(rgba_u8(250, 128, 114, 255), rgba(250, 128, 114, 1.0));
+/// assert_eq!(rgba_u8(250, 128, 114, 0), rgba(250, 128, 114, 0.0));
+/// ```
+pub const fn rgba_u8(r: u8, g: u8, b: u8, a: u8) -> RGBA {
+    RGBA {
+        r: Ratio::from_u8(r),
+        g: Ratio::from_u8(g),
+        b: Ratio::from_u8(b),
+        a: Ratio::from_u8(a),
+    }
+}
+
+/// Returns whether `a` and `b` are the same color give or take `tolerance`, e.g. to
+/// compare the results of conversions in a test suite without tripping over rounding.
+///
+/// Both colors are converted to RGBA, and each of the red, green, blue and alpha
+/// channels is compared as a `0-255` u8: the colors are equal when no channel differs
+/// by more than `tolerance`. A `tolerance` of `0` only accepts identical channels.
+///
+/// # Example
+/// ```
+/// use css_colors::{colors_approx_eq, hsl, rgb, rgba};
+///
+/// assert!(colors_approx_eq(rgb(255, 99, 71), rgb(254, 100, 71), 1));
+/// assert!(!colors_approx_eq(rgb(255, 99, 71), rgb(253, 99, 71), 1));
+/// assert!(colors_approx_eq(hsl(120, 100, 50), rgb(0, 255, 0), 1));
+/// assert!(!colors_approx_eq(rgb(255, 99, 71), rgba(255, 99, 71, 0.5), 1));
+/// ```
+pub fn colors_approx_eq<A: Color, B: Color>(a: A, b: B, tolerance: u8) -> bool {
+    let a = a.to_rgba();
+    let b = b.to_rgba();
+
+    [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)]
+        .iter()
+        .all(|&(lhs, rhs)| lhs.as_u8().abs_diff(rhs.as_u8()) <= tolerance)
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 /// A struct to represent how much red, green, and blue should be added to create a color.
 ///
@@ -64,8 +161,24 @@ pub struct RGB {
     pub b: Ratio,
 }
 
+/// Formats the color as `rgb(r, g, b)`. The alternate form, `{:#}`, follows it
+/// with the equivalent `hsl()` and hex notations, which helps when debugging conversions.
+///
+/// # Example
+/// ```
+/// use css_colors::rgb;
+///
+/// let tomato = rgb(255, 99, 71);
+///
+/// assert_eq!(format!("{}", tomato), "rgb(255, 99, 71)");
+/// assert_eq!(format!("{:#}", tomato), "rgb(255, 99, 71) / hsl(9, 100%, 64%) / #ff6347");
+/// ```
 impl fmt::Display for RGB {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return f.write_str(&model::all_notations(self.to_rgba(), self.to_hsla(), false));
+        }
+
         write!(
             f,
             "rgb({}, {}, {})",
@@ -76,6 +189,194 @@ impl fmt::Display for RGB {
     }
 }
 
+impl RGB {
+    /// Returns the color of `index` in the xterm 256-color palette, e.g. to turn the
+    /// ANSI colors captured from a terminal into RGB for further manipulation.
+    ///
+    /// * `0-15` are the system colors, whose values vary between terminals. These use
+    ///   the xterm defaults: `0-7` are black `(0, 0, 0)`, red `(205, 0, 0)`, green
+    ///   `(0, 205, 0)`, yellow `(205, 205, 0)`, blue `(0, 0, 238)`, magenta
+    ///   `(205, 0, 205)`, cyan `(0, 205, 205)` and white `(229, 229, 229)`, and `8-15`
+    ///   are their bright versions: `(127, 127, 127)`, `(255, 0, 0)`, `(0, 255, 0)`,
+    ///   `(255, 255, 0)`, `(92, 92, 255)`, `(255, 0, 255)`, `(0, 255, 255)` and
+    ///   `(255, 255, 255)`.
+    /// * `16-231` are a 6×6×6 color cube, where `index = 16 + 36 × r + 6 × g + b` and each
+    ///   channel takes one of the levels `0, 95, 135, 175, 215, 255`.
+    /// * `232-255` are a grayscale ramp from `(8, 8, 8)` to `(238, 238, 238)` in steps of 10.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, RGB};
+    ///
+    /// assert_eq!(RGB::from_ansi_256(1), rgb(205, 0, 0));
+    /// assert_eq!(RGB::from_ansi_256(16), rgb(0, 0, 0));
+    /// assert_eq!(RGB::from_ansi_256(208), rgb(255, 135, 0));
+    /// assert_eq!(RGB::from_ansi_256(232), rgb(8, 8, 8));
+    /// assert_eq!(RGB::from_ansi_256(255), rgb(238, 238, 238));
+    /// ```
+    pub fn from_ansi_256(index: u8) -> RGB {
+        match index {
+            0..=15 => {
+                let (r, g, b) = ANSI_SYSTEM_COLORS[usize::from(index)];
+                rgb(r, g, b)
+            }
+            16..=231 => {
+                let cube = usize::from(index - 16);
+
+                rgb(
+                    ANSI_CUBE_LEVELS[cube / 36],
+                    ANSI_CUBE_LEVELS[cube / 6 % 6],
+                    ANSI_CUBE_LEVELS[cube % 6],
+                )
+            }
+            _ => {
+                let grey = 8 + 10 * (index - 232);
+                rgb(grey, grey, grey)
+            }
+        }
+    }
+
+    /// Returns a copy of `self` with the red channel replaced.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::rgb;
+    ///
+    /// assert_eq!(rgb(255, 99, 71).with_red(0), rgb(0, 99, 71));
+    /// ```
+    pub fn with_red(self, red: u8) -> Self {
+        RGB {
+            r: Ratio::from_u8(red),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with the green channel replaced.
+    pub fn with_green(self, green: u8) -> Self {
+        RGB {
+            g: Ratio::from_u8(green),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with the blue channel replaced.
+    pub fn with_blue(self, blue: u8) -> Self {
+        RGB {
+            b: Ratio::from_u8(blue),
+            ..self
+        }
+    }
+
+    /// Returns the relative luminance of `self` paired with `self`, as a precomputed key
+    /// for sorting. Computing the luminance inside a `sort_by` comparison repeats the
+    /// work for every comparison; decorating each color once with this key avoids that.
+    /// See `sort_by_luminance` for a sort that does this internally.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::rgb;
+    ///
+    /// let mut keys: Vec<_> = [rgb(255, 255, 255), rgb(0, 0, 0)]
+    ///     .iter()
+    ///     .map(|color| color.luminance_sort_key())
+    ///     .collect();
+    ///
+    /// keys.sort_by(|lhs, rhs| lhs.0.partial_cmp(&rhs.0).unwrap());
+    ///
+    /// assert_eq!(keys[0], (0.0, rgb(0, 0, 0)));
+    /// ```
+    pub fn luminance_sort_key(self) -> (f32, RGB) {
+        (self.luminance().as_f32(), self)
+    }
+
+    /// Returns the HSL hue of `self`, the same angle as `self.to_hsl().h`, without working
+    /// out the saturation and lightness. This is handy for reading back the result of
+    /// `spin`, or for sorting and bucketing colors by hue. Achromatic colors (greys,
+    /// including black and white) have no hue and return `0°`.
+    ///
+    /// This differs from `Color::oklch_hue`, which returns the perceptual hue in the OKLCH
+    /// color space.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, rgb, Color};
+    ///
+    /// assert_eq!(rgb(255, 99, 71).hsl_hue(), deg(9));
+    /// assert_eq!(rgb(255, 99, 71).spin(deg(90)).hsl_hue(), deg(99));
+    /// assert_eq!(rgb(128, 128, 128).hsl_hue(), deg(0));
+    /// ```
+    pub fn hsl_hue(self) -> Angle {
+        self.to_rgba().hsl_hue()
+    }
+
+    /// Returns the HSL hue of `self` in degrees, without rounding it to a whole degree
+    /// as `hsl_hue` and `to_hsl` do. Comparing the two shows how much a conversion to
+    /// HSL rounded the hue, e.g. when a round trip through HSL drifts.
+    /// Achromatic colors return `0.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, Color};
+    ///
+    /// let color = rgb(255, 153, 0);
+    ///
+    /// assert_eq!(format!("{:.1}", color.hsl_hue_precise()), "36.0");
+    /// assert_eq!(format!("{:.1}", rgb(255, 99, 71).hsl_hue_precise()), "9.1");
+    /// ```
+    pub fn hsl_hue_precise(self) -> f32 {
+        self.to_rgba().hsl_hue_precise()
+    }
+
+    /// Makes `self` more colorful by adding `amount` to its OKLCH chroma, keeping its
+    /// lightness and hue. `amount` is on the same scale as `chroma()`: greys have a chroma
+    /// of `0.0`, and the most vivid sRGB colors reach a little over `0.3`, so e.g.
+    /// `color.boost_chroma(color.oklch_chroma() * 0.1)` makes `color` 10% more colorful.
+    ///
+    /// HSL saturation is only a crude proxy for colorfulness. Every fully saturated HSL
+    /// color already has 100% saturation, whether it is a vivid yellow or a dark blue,
+    /// and saturating by the same percentage changes their vividness by very different
+    /// amounts. Chroma is based on how colorful the eye sees a color to be, so boosting a
+    /// dark blue and a bright yellow by the same amount makes them comparably more vivid.
+    ///
+    /// This works in OKLCH rather than CIELCh. OKLCH is the space `chroma()` reports, so
+    /// `amount` can be derived from a color's own chroma, and its hues stay steadier than
+    /// CIELCh's as chroma changes (CIELCh notably drifts blues towards purple).
+    ///
+    /// When the boosted color would fall outside of the sRGB gamut, it is mapped back in
+    /// with `OKLCH::clamp_to_srgb`, which reduces the chroma so the lightness and hue are
+    /// kept. Greys have no hue and are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, Color};
+    ///
+    /// let muted = rgb(120, 100, 90);
+    ///
+    /// assert!((muted.boost_chroma(0.05).oklch_chroma() - muted.oklch_chroma() - 0.05).abs() < 0.005);
+    /// assert_eq!(rgb(128, 128, 128).boost_chroma(0.05), rgb(128, 128, 128));
+    /// ```
+    pub fn boost_chroma(self, amount: f32) -> RGB {
+        oklch::adjust_chroma(self, amount)
+    }
+
+    /// Makes `self` less colorful by subtracting `amount` from its OKLCH chroma, keeping
+    /// its lightness and hue. The chroma stops at `0.0`, which gives the grey of the same
+    /// lightness. See `boost_chroma` for details.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, Color};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert!((tomato.oklch_chroma() - tomato.reduce_chroma(0.05).oklch_chroma() - 0.05).abs() < 0.005);
+    /// assert!(tomato.reduce_chroma(1.0).oklch_chroma() < 0.01);
+    /// ```
+    pub fn reduce_chroma(self, amount: f32) -> RGB {
+        oklch::adjust_chroma(self, -amount)
+    }
+}
+
 impl Color for RGB {
     type Alpha = RGBA;
 
@@ -179,8 +480,27 @@ pub struct RGBA {
     pub a: Ratio,
 }
 
+/// Formats the color as `rgba(r, g, b, a)`. The alternate form, `{:#}`, follows it
+/// with the equivalent `hsla()` and hex notations, which helps when debugging conversions.
+///
+/// # Example
+/// ```
+/// use css_colors::rgba;
+///
+/// let tomato = rgba(255, 99, 71, 0.5);
+///
+/// assert_eq!(format!("{}", tomato), "rgba(255, 99, 71, 0.50)");
+/// assert_eq!(
+///     format!("{:#}", tomato),
+///     "rgba(255, 99, 71, 0.50) / hsla(9, 100%, 64%, 0.50) / #ff634780"
+/// );
+/// ```
 impl fmt::Display for RGBA {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return f.write_str(&model::all_notations(*self, self.to_hsla(), true));
+        }
+
         write!(
             f,
             "rgba({}, {}, {}, {:.02})",
@@ -192,6 +512,126 @@ impl fmt::Display for RGBA {
     }
 }
 
+impl RGBA {
+    /// Returns a copy of `self` with the red channel replaced.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::rgba;
+    ///
+    /// assert_eq!(rgba(255, 99, 71, 0.5).with_red(0).with_alpha_u8(255), rgba(0, 99, 71, 1.0));
+    /// ```
+    pub fn with_red(self, red: u8) -> Self {
+        RGBA {
+            r: Ratio::from_u8(red),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with the green channel replaced.
+    pub fn with_green(self, green: u8) -> Self {
+        RGBA {
+            g: Ratio::from_u8(green),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with the blue channel replaced.
+    pub fn with_blue(self, blue: u8) -> Self {
+        RGBA {
+            b: Ratio::from_u8(blue),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with the alpha channel replaced, expressed as a u8
+    /// between `0-255`. To set the alpha channel from a `Ratio`, use `fade`.
+    pub fn with_alpha_u8(self, alpha: u8) -> Self {
+        RGBA {
+            a: Ratio::from_u8(alpha),
+            ..self
+        }
+    }
+
+    /// Returns the HSL hue of `self`, the same angle as `self.to_hsla().h`. See
+    /// `RGB::hsl_hue` for details. Achromatic colors return `0°`.
+    pub fn hsl_hue(self) -> Angle {
+        deg(self.hsl_hue_precise().round() as i32)
+    }
+
+    /// Returns the HSL hue of `self` in degrees, without rounding it to a whole degree.
+    /// See `RGB::hsl_hue_precise` for details. Achromatic colors return `0.0`.
+    pub fn hsl_hue_precise(self) -> f32 {
+        let r = self.r.as_f32();
+        let g = self.g.as_f32();
+        let b = self.b.as_f32();
+        let (max, min) = max_min(r, g, b);
+
+        if max == min {
+            return 0.0;
+        }
+
+        hue_degrees(r, g, b, max, min).rem_euclid(360.0)
+    }
+
+    /// Composites `over` on top of `self`, as if painting a translucent layer over
+    /// another, using the Porter-Duff "source-over" operator. The result is only as
+    /// transparent as both layers together: `a_out = a_over + a_self * (1 - a_over)`.
+    /// Two fully transparent layers composite to `rgba(0, 0, 0, 0.0)`.
+    ///
+    /// Unlike `mix`, which averages two colors, stacking a fully opaque color
+    /// always hides whatever is underneath it.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba, Color};
+    ///
+    /// let white = rgb(255, 255, 255).to_rgba();
+    ///
+    /// assert_eq!(white.stack(rgba(0, 0, 0, 0.5)), rgba(127, 127, 127, 1.0));
+    /// assert_eq!(white.stack(rgba(255, 99, 71, 1.0)), rgba(255, 99, 71, 1.0));
+    /// ```
+    pub fn stack(self, over: RGBA) -> RGBA {
+        let a_over = over.a.as_f32();
+        let a_under = self.a.as_f32() * (1.0 - a_over);
+        let a = a_over + a_under;
+
+        if a == 0.0 {
+            return rgba(0, 0, 0, 0.0);
+        }
+
+        let composite = |over: Ratio, under: Ratio| {
+            Ratio::clamp((over.as_f32() * a_over + under.as_f32() * a_under) / a)
+        };
+
+        RGBA {
+            r: composite(over.r, self.r),
+            g: composite(over.g, self.g),
+            b: composite(over.b, self.b),
+            a: Ratio::clamp(a),
+        }
+    }
+
+    /// Draws `self` onto the opaque background `bg`, using the alpha channel of `self`
+    /// as the weight, and returns the opaque result. This is the source-over operator
+    /// of `stack` with an opaque backdrop, which reduces to
+    /// `out = fg * a + bg * (1 - a)` for each of the red, green and blue channels.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba};
+    ///
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(rgba(0, 0, 0, 0.5).composite_onto(white), rgb(127, 127, 127));
+    /// assert_eq!(rgba(255, 99, 71, 1.0).composite_onto(white), rgb(255, 99, 71));
+    /// assert_eq!(rgba(255, 99, 71, 0.0).composite_onto(white), white);
+    /// ```
+    pub fn composite_onto(self, bg: RGB) -> RGB {
+        bg.to_rgba().stack(self).to_rgb()
+    }
+}
+
 impl Color for RGBA {
     type Alpha = Self;
 
@@ -227,61 +667,14 @@ impl Color for RGBA {
             };
         }
 
-        // Otherwise, to determine luminosity, we conver the RGB values into a
-        // percentage value, find the max and the min of those values, sum them
-        // together, and divide by 2.
-        let r = self.r.as_f32();
-        let g = self.g.as_f32();
-        let b = self.b.as_f32();
-
-        let max = if r > g && r > b {
-            r
-        } else if g > b {
-            g
-        } else {
-            b
-        };
-
-        let min = if r < g && r < b {
-            r
-        } else if g < b {
-            g
-        } else {
-            b
-        };
-
-        let luminosity = (max + min) / 2.0;
-
-        // To find the saturation, we look at the max and min values.
-        // If the max and the min are the same, there is no saturation to the color.
-        // Otherwise, we calculate the saturation based on if the luminosity is
-        // greater than or less than 0.5.
-        let saturation = if max == min {
-            0.0
-        } else if luminosity < 0.5 {
-            (max - min) / (max + min)
-        } else {
-            (max - min) / (2.0 - (max + min))
-        };
-
-        // To calculate the hue, we look at which value (r, g, or b) is the max.
-        // Based on that, we subtract the difference between the other two values,
-        // adding 120 or 240 deg to account for the degrees on the color wheel, and
-        // then dividing that by the difference between the max and the min values.
-        // Finally, we multiply the hue value by 60 to convert it to degrees on
-        // the color wheel, accounting for negative hues as well.
-        let hue = if max == r {
-            60.0 * (g - b) / (max - min)
-        } else if max == g {
-            120.0 + 60.0 * (b - r) / (max - min)
-        } else {
-            240.0 + 60.0 * (r - g) / (max - min)
-        };
+        let (hue, saturation, luminosity) = hsl_channels(r.as_f32(), g.as_f32(), b.as_f32());
 
+        // Floating point error can push the saturation of fully saturated colors
+        // just past 1.0, so clamp rather than panic.
         HSLA {
             h: deg(hue.round() as i32),
-            s: Ratio::from_f32(saturation),
-            l: Ratio::from_f32(luminosity),
+            s: Ratio::clamp(saturation),
+            l: Ratio::clamp(luminosity),
             a,
         }
     }
@@ -345,7 +738,9 @@ impl Color for RGBA {
         let a = a_lhs.as_f32() - a_rhs.as_f32();
 
         // Find the combined rgb_weight, taking into account the user's passed-in weight and alpha (range of [-1, 1]).
-        let rgb_weight = if w * a == -1.0 {
+        // When `w * a` is -1, the division below would be by zero, so the alpha is ignored.
+        // The comparison allows for floating point error defensively; see `MIX_EPSILON`.
+        let rgb_weight = if (1.0 + w * a).abs() < MIX_EPSILON {
             w
         } else {
             (w + a) / (1.0 + w * a)
@@ -380,4 +775,102 @@ impl Color for RGBA {
     fn greyscale(self) -> Self {
         self.to_hsla().greyscale().to_rgba()
     }
+
+    // The grey is found by converting the relative luminance, which is in linear
+    // light, back into a gamma-encoded value.
+    fn fade_to_gray(self, amount: Ratio) -> Self {
+        let gray = from_linear(self.luminance().as_f32());
+        let RGBA { r, g, b, a } = self;
+
+        let amount = amount.as_f32();
+        let fade = |channel: Ratio| {
+            let channel = channel.as_f32();
+            Ratio::clamp(channel + (gray - channel) * amount)
+        };
+
+        RGBA {
+            r: fade(r),
+            g: fade(g),
+            b: fade(b),
+            a,
+        }
+    }
+
+    fn grayscale_weighted(self, coefficients: (f32, f32, f32)) -> Self {
+        let RGBA { r, g, b, a } = self;
+        let (wr, wg, wb) = coefficients;
+        let luma = Ratio::clamp(wr * r.as_f32() + wg * g.as_f32() + wb * b.as_f32());
+
+        RGBA {
+            r: luma,
+            g: luma,
+            b: luma,
+            a,
+        }
+    }
+}
+
+// Converts the red, green, and blue channels, in the `0.0-1.0` range, into the hue in
+// degrees, and the saturation and luminosity in the `0.0-1.0` range. Greys have a hue of 0.
+pub(crate) fn hsl_channels(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    // To determine luminosity, we find the max and the min of the channels,
+    // sum them together, and divide by 2.
+    let (max, min) = max_min(r, g, b);
+
+    let luminosity = (max + min) / 2.0;
+
+    // If the max and the min are the same, there is no saturation or hue to the color.
+    if max == min {
+        return (0.0, 0.0, luminosity);
+    }
+
+    // Otherwise, we calculate the saturation based on if the luminosity is
+    // greater than or less than 0.5.
+    let saturation = if luminosity < 0.5 {
+        (max - min) / (max + min)
+    } else {
+        (max - min) / (2.0 - (max + min))
+    };
+
+    let hue = hue_degrees(r, g, b, max, min);
+
+    (hue, saturation, luminosity)
+}
+
+// Returns the largest and the smallest of the r, g, and b channels.
+fn max_min(r: f32, g: f32, b: f32) -> (f32, f32) {
+    let max = if r > g && r > b {
+        r
+    } else if g > b {
+        g
+    } else {
+        b
+    };
+
+    let min = if r < g && r < b {
+        r
+    } else if g < b {
+        g
+    } else {
+        b
+    };
+
+    (max, min)
+}
+
+// Returns the hue in degrees, which may be negative. `max` and `min` must differ.
+fn hue_degrees(r: f32, g: f32, b: f32, max: f32, min: f32) -> f32 {
+    // To calculate the hue, we look at which value (r, g, or b) is the max.
+    // Based on that, we subtract the difference between the other two values,
+    // adding 120 or 240 deg to account for the degrees on the color wheel, and
+    // then dividing that by the difference between the max and the min values.
+    // Finally, we multiply the hue value by 60 to convert it to degrees on
+    // the color wheel, accounting for negative hues as well.
+    if max == r {
+        60.0 * (g - b) / (max - min)
+    } else if max == g {
+        120.0 + 60.0 * (b - r) / (max - min)
+    } else {
+        240.0 + 60.0 * (r - g) / (max - min)
+    }
 }
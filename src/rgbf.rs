@@ -0,0 +1,274 @@
+use super::hsl::hsl_to_rgb_channels;
+use super::rgb::hsl_channels;
+use super::{Ratio, RGB};
+
+/// Constructs an RGBf color from red, green, and blue channels in the `0.0-1.0` range.
+///
+/// Channels outside of the range are clamped. Non-finite values will cause a panic.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, rgbf};
+///
+/// assert_eq!(rgbf(1.0, 0.5, 0.0).to_rgb(), rgb(255, 128, 0));
+/// assert_eq!(rgbf(1.5, -0.5, 0.0), rgbf(1.0, 0.0, 0.0));
+/// ```
+pub fn rgbf(r: f32, g: f32, b: f32) -> RGBf {
+    assert!(
+        r.is_finite() && g.is_finite() && b.is_finite(),
+        "Invalid value for RGBf channel"
+    );
+
+    RGBf {
+        r: r.clamp(0.0, 1.0),
+        g: g.clamp(0.0, 1.0),
+        b: b.clamp(0.0, 1.0),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct to represent an RGB color with `f32` channels, for pipelines that apply
+/// many operations in a row.
+///
+/// Every operation on `RGB` rounds its channels to the nearest `Ratio`, i.e. to one
+/// of 256 steps. A single rounding is invisible, but a long chain of them (e.g.
+/// repeatedly lightening, spinning, and mixing colors to build a gradient) adds up
+/// to visible banding. `RGBf` runs the same operations on floats instead, so a
+/// pipeline can convert into it with `RGBf::from`, do all of its work, and round
+/// once at the end with `to_rgb`.
+///
+/// Valid values for r, g, and b range between `0.0-1.0`. The operations keep the
+/// channels within that range.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, RGBf};
+///
+/// let tomato = rgb(255, 99, 71);
+/// let result = RGBf::from(tomato).lighten(0.2).darken(0.2).to_rgb();
+///
+/// assert_eq!(result, tomato);
+/// ```
+pub struct RGBf {
+    // red
+    pub r: f32,
+
+    // green
+    pub g: f32,
+
+    // blue
+    pub b: f32,
+}
+
+impl RGBf {
+    /// Rounds `self` into an `RGB` color. This is the only step of an `RGBf`
+    /// pipeline that quantizes the channels.
+    pub fn to_rgb(self) -> RGB {
+        RGB {
+            r: Ratio::clamp(self.r),
+            g: Ratio::clamp(self.g),
+            b: Ratio::clamp(self.b),
+        }
+    }
+
+    /// Increases the HSL saturation of `self` by `amount`, between `0.0` and `1.0`.
+    /// Like `Color::saturate`, the saturation is capped at 100%.
+    pub fn saturate(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        from_hsl(h, s + amount, l)
+    }
+
+    /// Decreases the HSL saturation of `self` by `amount`, between `0.0` and `1.0`.
+    /// Like `Color::desaturate`, the saturation stops at 0%.
+    pub fn desaturate(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        from_hsl(h, s - amount, l)
+    }
+
+    /// Increases the HSL lightness of `self` by `amount`, between `0.0` and `1.0`.
+    /// Like `Color::lighten`, the lightness is capped at 100%.
+    pub fn lighten(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        from_hsl(h, s, l + amount)
+    }
+
+    /// Decreases the HSL lightness of `self` by `amount`, between `0.0` and `1.0`.
+    /// Like `Color::darken`, the lightness stops at 0%.
+    pub fn darken(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        from_hsl(h, s, l - amount)
+    }
+
+    /// Rotates the hue of `self` by `degrees`, which may be fractional or negative.
+    /// Like `Color::spin`, the hue wraps around the color wheel.
+    pub fn spin(self, degrees: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        from_hsl(h + degrees, s, l)
+    }
+
+    /// Mixes `self` with `other`, where `weight` (between `0.0` and `1.0`) is the
+    /// proportion of `self`. Since `RGBf` has no alpha channel, this is a plain
+    /// weighted average of the channels.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::rgbf;
+    ///
+    /// let black = rgbf(0.0, 0.0, 0.0);
+    /// let white = rgbf(1.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(black.mix(white, 0.25), rgbf(0.75, 0.75, 0.75));
+    /// ```
+    pub fn mix(self, other: RGBf, weight: f32) -> Self {
+        let mix = |lhs: f32, rhs: f32| (lhs * weight + rhs * (1.0 - weight)).clamp(0.0, 1.0);
+
+        RGBf {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+        }
+    }
+
+    /// Mixes `self` with white, where `weight` is the proportion of `self`.
+    pub fn tint(self, weight: f32) -> Self {
+        self.mix(rgbf(1.0, 1.0, 1.0), weight)
+    }
+
+    /// Mixes `self` with black, where `weight` is the proportion of `self`.
+    pub fn shade(self, weight: f32) -> Self {
+        self.mix(rgbf(0.0, 0.0, 0.0), weight)
+    }
+
+    /// Removes all saturation from `self`, keeping its HSL lightness.
+    pub fn greyscale(self) -> Self {
+        let (_, _, l) = self.to_hsl();
+        RGBf { r: l, g: l, b: l }
+    }
+
+    fn to_hsl(self) -> (f32, f32, f32) {
+        hsl_channels(self.r, self.g, self.b)
+    }
+}
+
+impl From<RGB> for RGBf {
+    fn from(color: RGB) -> Self {
+        RGBf {
+            r: color.r.as_f32(),
+            g: color.g.as_f32(),
+            b: color.b.as_f32(),
+        }
+    }
+}
+
+impl From<RGBf> for RGB {
+    fn from(color: RGBf) -> Self {
+        color.to_rgb()
+    }
+}
+
+// Converts from HSL, clamping the saturation and lightness like `Ratio`'s `Add` and `Sub` do.
+fn from_hsl(h: f32, s: f32, l: f32) -> RGBf {
+    let (r, g, b) = hsl_to_rgb_channels(h, s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+
+    RGBf {
+        r: r.clamp(0.0, 1.0),
+        g: g.clamp(0.0, 1.0),
+        b: b.clamp(0.0, 1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {percent, rgb, rgbf, Color, RGBf, RGB};
+
+    #[test]
+    fn converts_to_and_from_rgb() {
+        for color in &[
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+            rgb(255, 99, 71),
+            rgb(17, 17, 255),
+        ] {
+            assert_eq!(RGBf::from(*color).to_rgb(), *color);
+            assert_eq!(RGB::from(RGBf::from(*color)), *color);
+        }
+    }
+
+    // The `Ratio` operations also round the HSL components, so the results may be one step apart.
+    fn assert_close(lhs: RGBf, rhs: RGB) {
+        let lhs = lhs.to_rgb();
+        let close = |lhs: u8, rhs: u8| (i16::from(lhs) - i16::from(rhs)).abs() <= 1;
+
+        assert!(
+            close(lhs.r.as_u8(), rhs.r.as_u8())
+                && close(lhs.g.as_u8(), rhs.g.as_u8())
+                && close(lhs.b.as_u8(), rhs.b.as_u8()),
+            "{:?} is not close to {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn matches_rgb_operations() {
+        let tomato = rgb(255, 99, 71);
+        let float = RGBf::from(tomato);
+
+        assert_close(float.saturate(0.1), tomato.saturate(percent(10)));
+        assert_close(float.desaturate(0.5), tomato.desaturate(percent(50)));
+        assert_close(float.lighten(0.1), tomato.lighten(percent(10)));
+        assert_close(float.darken(0.3), tomato.darken(percent(30)));
+        assert_close(float.greyscale(), tomato.greyscale());
+        assert_eq!(float.lighten(1.0).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(float.darken(1.0).to_rgb(), rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn spins_by_fractional_degrees() {
+        let red = rgbf(1.0, 0.0, 0.0);
+
+        assert_eq!(red.spin(120.0).to_rgb(), rgb(0, 255, 0));
+        assert_eq!(red.spin(-120.0).to_rgb(), rgb(0, 0, 255));
+        assert_eq!(red.spin(720.0).to_rgb(), rgb(255, 0, 0));
+        assert_eq!(red.spin(0.5).spin(0.5).to_rgb(), red.spin(1.0).to_rgb());
+    }
+
+    #[test]
+    fn does_not_drift_over_many_operations() {
+        let blue = rgb(40, 90, 200);
+        let mut float = RGBf::from(blue);
+        let mut quantized = blue;
+
+        for _ in 0..20 {
+            float = float.lighten(0.01);
+            quantized = quantized.lighten(percent(1));
+        }
+
+        for _ in 0..20 {
+            float = float.darken(0.01);
+            quantized = quantized.darken(percent(1));
+        }
+
+        assert_eq!(float.to_rgb(), blue);
+        assert_ne!(quantized, blue);
+
+        let mut float = RGBf::from(blue);
+
+        for _ in 0..12 {
+            float = float.spin(30.0).darken(0.2).lighten(0.2);
+        }
+
+        assert_eq!(float.to_rgb(), blue);
+    }
+
+    #[test]
+    fn can_mix() {
+        let black = rgbf(0.0, 0.0, 0.0);
+        let white = rgbf(1.0, 1.0, 1.0);
+
+        assert_eq!(black.mix(white, 1.0), black);
+        assert_eq!(black.mix(white, 0.0), white);
+        assert_eq!(black.tint(0.5), rgbf(0.5, 0.5, 0.5));
+        assert_eq!(white.shade(0.5), rgbf(0.5, 0.5, 0.5));
+    }
+}